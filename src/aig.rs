@@ -0,0 +1,715 @@
+use crate::circuit::{bit_tables, build_funcs};
+use crate::parser::Expr;
+use crate::Token;
+use std::collections::HashMap;
+
+/// an edge of an `Aig`: a node and if it's complemented (negated)
+///
+/// the value is `2 * node + complemented` like the literals of the AIGER format,
+/// node 0 is the constant false so `AigLit::FALSE` is 0 and `AigLit::TRUE` is 1
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub struct AigLit(usize);
+
+impl AigLit {
+    pub const FALSE: AigLit = AigLit(0);
+    pub const TRUE: AigLit = AigLit(1);
+
+    pub fn new(node: usize, complemented: bool) -> Self {
+        AigLit(node << 1 | complemented as usize)
+    }
+
+    /// the index of the node
+    pub fn node(&self) -> usize {
+        self.0 >> 1
+    }
+
+    pub fn is_complemented(&self) -> bool {
+        self.0 & 1 == 1
+    }
+}
+
+impl std::ops::Not for AigLit {
+    type Output = AigLit;
+
+    fn not(self) -> AigLit {
+        AigLit(self.0 ^ 1)
+    }
+}
+
+/// a node of an `Aig`
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+pub enum AigNode {
+    /// the constant false, always node 0
+    Const,
+    /// the index of the input
+    Input(usize),
+    And(AigLit, AigLit),
+}
+
+/// an [And-Inverter Graph]: every node is an input or an `AND` of two (possibly negated) nodes
+///
+/// equal nodes are only stored once (structural hashing) and
+/// trivial `AND`s (with a constant, twice the same input or an input and its negation) are not created,
+/// the nodes are in topological order
+///
+/// it can be written to and read from the [AIGER] format (ASCII and binary) that is used by ABC
+/// and many model checkers, latches are not supported
+///
+/// [And-Inverter Graph]:https://en.wikipedia.org/wiki/And-inverter_graph
+/// [AIGER]:http://fmv.jku.at/aiger/
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// let mut aig = Aig::new();
+/// let a = aig.add_input("a").unwrap();
+/// let b = aig.add_input("b").unwrap();
+/// let or = aig.or(a, b);
+/// aig.add_output("or", or).unwrap();
+///
+/// // !(!a & !b)
+/// assert_eq!(aig.nodes()[3], AigNode::And(!a, !b));
+/// assert_eq!(or, AigLit::new(3, true));
+/// assert_eq!(aig.tables(), Ok(vec![vec![false, true, true, true]]));
+/// assert_eq!(aig.to_aag(), "aag 3 2 0 1 1\n2\n4\n7\n6 5 3\ni0 a\ni1 b\no0 or\n");
+/// ```
+#[derive(PartialEq, Debug, Clone, Eq)]
+pub struct Aig {
+    inputs: Vec<String>,
+    outputs: Vec<(String, AigLit)>,
+    nodes: Vec<AigNode>,
+    hash: HashMap<(AigLit, AigLit), usize>,
+}
+
+impl Default for Aig {
+    fn default() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            nodes: vec![AigNode::Const],
+            hash: HashMap::new(),
+        }
+    }
+}
+
+impl Aig {
+    /// an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// builds a graph with an output for every function named `out0`, `out1`, ...
+    ///
+    /// see `from_named_funcs`
+    pub fn from_funcs(funcs: &[Vec<Token>]) -> Result<Self, String> {
+        let named: Vec<(String, Vec<Token>)> = funcs
+            .iter()
+            .enumerate()
+            .map(|(i, func)| (format!("out{}", i), func.clone()))
+            .collect();
+        Self::from_named_funcs(&named)
+    }
+
+    /// builds a graph with an output for every function,
+    /// the inputs are the variables of all functions (sorted like `get_names`)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a ⊕ b
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::Xor,
+    ///     Token::Var("b".to_string()),
+    /// ];
+    ///
+    /// let aig = Aig::from_named_funcs(&[("x".to_string(), func.clone())]).unwrap();
+    /// assert_eq!(aig.tables(), Ok(vec![parse(&func).unwrap()]));
+    /// // !(!(a & !b) & !(!a & b))
+    /// assert_eq!(aig.nodes().len(), 6);
+    /// ```
+    pub fn from_named_funcs(funcs: &[(String, Vec<Token>)]) -> Result<Self, String> {
+        let (names, exprs) = build_funcs(funcs)?;
+
+        let mut aig = Self::new();
+        for name in &names {
+            aig.add_input(name)?;
+        }
+        for ((name, _), expr) in funcs.iter().zip(exprs) {
+            let lit = aig.add_expr(&expr);
+            aig.add_output(name, lit)?;
+        }
+        Ok(aig)
+    }
+
+    fn add_expr(&mut self, expr: &Expr) -> AigLit {
+        match expr {
+            Expr::Const(value) => AigLit(*value as usize),
+            Expr::Var(index) => AigLit::new(*index + 1, false),
+            Expr::Not(left) => !self.add_expr(left),
+            Expr::Binary(operator, a, b) => {
+                let a = self.add_expr(a);
+                let b = self.add_expr(b);
+                match operator {
+                    Token::And => self.and(a, b),
+                    Token::Or => self.or(a, b),
+                    Token::Xor => self.xor(a, b),
+                    Token::Eq => !self.xor(a, b),
+                    Token::Nand => !self.and(a, b),
+                    Token::Nor => !self.or(a, b),
+                    Token::ImplicAB => self.or(!a, b),
+                    Token::ImplicBA => self.or(a, !b),
+                    _ => unreachable!("not a binary operator"),
+                }
+            }
+        }
+    }
+
+    /// adds an input, inputs have to be added before any `AND`
+    pub fn add_input(&mut self, name: &str) -> Result<AigLit, String> {
+        if self.nodes.len() != self.inputs.len() + 1 {
+            return Err("inputs have to be added before the gates".to_string());
+        }
+        if self.inputs.iter().any(|input| input == name) {
+            return Err(format!("duplicate input {}", name));
+        }
+        self.inputs.push(name.to_string());
+        self.nodes.push(AigNode::Input(self.inputs.len() - 1));
+        Ok(AigLit::new(self.nodes.len() - 1, false))
+    }
+
+    /// the `AND` of two edges
+    ///
+    /// panics if an edge points to a node that doesn't exist
+    pub fn and(&mut self, a: AigLit, b: AigLit) -> AigLit {
+        assert!(a.node() < self.nodes.len() && b.node() < self.nodes.len());
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        if a == AigLit::FALSE || a == !b {
+            return AigLit::FALSE;
+        }
+        if a == AigLit::TRUE || a == b {
+            return b;
+        }
+        if let Some(&node) = self.hash.get(&(a, b)) {
+            return AigLit::new(node, false);
+        }
+        self.nodes.push(AigNode::And(a, b));
+        self.hash.insert((a, b), self.nodes.len() - 1);
+        AigLit::new(self.nodes.len() - 1, false)
+    }
+
+    /// `!(!a & !b)`
+    pub fn or(&mut self, a: AigLit, b: AigLit) -> AigLit {
+        !self.and(!a, !b)
+    }
+
+    /// `!(!(a & !b) & !(!a & b))`
+    pub fn xor(&mut self, a: AigLit, b: AigLit) -> AigLit {
+        let left = self.and(a, !b);
+        let right = self.and(!a, b);
+        self.or(left, right)
+    }
+
+    /// adds an output
+    pub fn add_output(&mut self, name: &str, lit: AigLit) -> Result<(), String> {
+        if self.outputs.iter().any(|(output, _)| output == name) {
+            return Err(format!("duplicate output {}", name));
+        }
+        if lit.node() >= self.nodes.len() {
+            return Err(format!("node {} doesn't exist", lit.node()));
+        }
+        self.outputs.push((name.to_string(), lit));
+        Ok(())
+    }
+
+    /// the names of the inputs, input `i` is node `i + 1`
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// the names and edges of the outputs
+    pub fn outputs(&self) -> &[(String, AigLit)] {
+        &self.outputs
+    }
+
+    /// all nodes in topological order, starting with the constant and the inputs
+    pub fn nodes(&self) -> &[AigNode] {
+        &self.nodes
+    }
+
+    /// the number of `AND` nodes
+    pub fn and_count(&self) -> usize {
+        self.nodes.len() - self.inputs.len() - 1
+    }
+
+    /// computes the outputs for a single assignment of the inputs
+    pub fn simulate(&self, inputs: &[bool]) -> Result<Vec<bool>, String> {
+        let bits: Vec<u64> = inputs.iter().map(|&b| if b { !0 } else { 0 }).collect();
+        Ok(self
+            .simulate_bits(&bits)?
+            .iter()
+            .map(|&bits| bits & 1 == 1)
+            .collect())
+    }
+
+    /// computes the outputs for 64 assignments at once, every bit is one assignment
+    pub fn simulate_bits(&self, inputs: &[u64]) -> Result<Vec<u64>, String> {
+        if inputs.len() != self.inputs.len() {
+            return Err(format!(
+                "expected {} values got {}",
+                self.inputs.len(),
+                inputs.len()
+            ));
+        }
+
+        let mut values: Vec<u64> = Vec::with_capacity(self.nodes.len());
+        let lit = |values: &[u64], lit: AigLit| {
+            values[lit.node()] ^ if lit.is_complemented() { !0 } else { 0 }
+        };
+        for node in &self.nodes {
+            let value = match node {
+                AigNode::Const => 0,
+                AigNode::Input(index) => inputs[*index],
+                AigNode::And(a, b) => lit(&values, *a) & lit(&values, *b),
+            };
+            values.push(value);
+        }
+        Ok(self
+            .outputs
+            .iter()
+            .map(|(_, output)| lit(&values, *output))
+            .collect())
+    }
+
+    /// the compressed table of every output, in the same layout `parse_full` returns
+    pub fn tables(&self) -> Result<Vec<Vec<bool>>, String> {
+        bit_tables(self.inputs.len(), self.outputs.len(), |inputs| {
+            self.simulate_bits(inputs)
+        })
+    }
+
+    /// converts an edge back into a function over the inputs,
+    /// a negated `AND` with a negated input is written as `OR`: `!(a & !b)` is `!a | b`
+    ///
+    /// note shared nodes are written every time they are used,
+    /// so the function can be a lot bigger than the graph
+    pub fn func(&self, lit: AigLit) -> Vec<Token> {
+        self.expr(lit).to_tokens(&self.inputs)
+    }
+
+    /// converts every output back into a function, see `func`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a → b
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::ImplicAB,
+    ///     Token::Var("b".to_string()),
+    /// ];
+    ///
+    /// let aig = Aig::from_funcs(&[func]).unwrap();
+    /// assert_eq!(
+    ///     aig.to_funcs(),
+    ///     vec![vec![
+    ///         Token::Not,
+    ///         Token::Var("a".to_string()),
+    ///         Token::Or,
+    ///         Token::Var("b".to_string()),
+    ///     ]]
+    /// );
+    /// ```
+    pub fn to_funcs(&self) -> Vec<Vec<Token>> {
+        self.outputs
+            .iter()
+            .map(|(_, output)| self.func(*output))
+            .collect()
+    }
+
+    fn expr(&self, lit: AigLit) -> Expr {
+        let expr = match self.nodes[lit.node()] {
+            AigNode::Const => Expr::Const(false),
+            AigNode::Input(index) => Expr::Var(index),
+            AigNode::And(a, b)
+                if lit.is_complemented() && (a.is_complemented() || b.is_complemented()) =>
+            {
+                return Expr::Binary(Token::Or, Box::new(self.expr(!a)), Box::new(self.expr(!b)));
+            }
+            AigNode::And(a, b) => {
+                Expr::Binary(Token::And, Box::new(self.expr(a)), Box::new(self.expr(b)))
+            }
+        };
+        if lit.is_complemented() {
+            Expr::Not(Box::new(expr)).fold()
+        } else {
+            expr
+        }
+    }
+
+    fn header(&self, format: &str) -> String {
+        format!(
+            "{} {} {} 0 {} {}\n",
+            format,
+            self.inputs.len() + self.and_count(),
+            self.inputs.len(),
+            self.outputs.len(),
+            self.and_count()
+        )
+    }
+
+    fn symbols(&self) -> String {
+        let mut out = String::new();
+        for (i, name) in self.inputs.iter().enumerate() {
+            out += &format!("i{} {}\n", i, name);
+        }
+        for (i, (name, _)) in self.outputs.iter().enumerate() {
+            out += &format!("o{} {}\n", i, name);
+        }
+        out
+    }
+
+    /// writes the graph in the ASCII AIGER format (`aag`) with a symbol table
+    pub fn to_aag(&self) -> String {
+        // the inputs come before the gates so the nodes are already numbered like in the file
+        let mut out = self.header("aag");
+        for i in 0..self.inputs.len() {
+            out += &format!("{}\n", (i + 1) << 1);
+        }
+        for (_, output) in &self.outputs {
+            out += &format!("{}\n", output.0);
+        }
+        for (node, and) in self.nodes.iter().enumerate() {
+            if let AigNode::And(a, b) = and {
+                out += &format!("{} {} {}\n", node << 1, b.0, a.0);
+            }
+        }
+        out + &self.symbols()
+    }
+
+    /// writes the graph in the binary AIGER format (`aig`) with a symbol table
+    pub fn to_aig(&self) -> Vec<u8> {
+        let mut out = self.header("aig");
+        for (_, output) in &self.outputs {
+            out += &format!("{}\n", output.0);
+        }
+        let mut out = out.into_bytes();
+        for (node, and) in self.nodes.iter().enumerate() {
+            // the children are sorted so b is the larger literal
+            if let AigNode::And(a, b) = and {
+                write_delta(&mut out, (node << 1) - b.0);
+                write_delta(&mut out, b.0 - a.0);
+            }
+        }
+        out.extend(self.symbols().into_bytes());
+        out
+    }
+
+    /// reads a graph in the ASCII (`aag`) or binary (`aig`) AIGER format
+    ///
+    /// inputs and outputs without a name in the symbol table are named `i0`, `i1`, ... and `o0`, `o1`, ...,
+    /// the `AND`s are hashed and simplified again so the graph can have fewer nodes than the file
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // the output is !(a & !b)
+    /// let aig = Aig::from_aiger(b"aag 3 2 0 1 1\n2\n4\n7\n6 5 2\n").unwrap();
+    /// assert_eq!(aig.inputs(), &["i0".to_string(), "i1".to_string()]);
+    /// assert_eq!(aig.tables(), Ok(vec![vec![true, true, false, true]]));
+    /// ```
+    pub fn from_aiger(data: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader { data, pos: 0 };
+        let header = reader.line()?;
+        let mut fields = header.split_whitespace();
+        let binary = match fields.next() {
+            Some("aag") => false,
+            Some("aig") => true,
+            _ => return Err("expected aag or aig header".to_string()),
+        };
+        let numbers = fields
+            .map(|field| {
+                field
+                    .parse::<usize>()
+                    .map_err(|_| format!("invalid number {}", field))
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+        if numbers.len() < 5 {
+            return Err("expected M I L O A in the header".to_string());
+        }
+        let (max, inputs, latches, outputs, ands) =
+            (numbers[0], numbers[1], numbers[2], numbers[3], numbers[4]);
+        if latches != 0 {
+            return Err("latches are not supported".to_string());
+        }
+        if numbers[5..].iter().any(|&n| n != 0) {
+            return Err(
+                "bad state, invariant, justice and fairness properties are not supported"
+                    .to_string(),
+            );
+        }
+        let total = [inputs, latches, outputs, ands]
+            .iter()
+            .try_fold(0_usize, |sum, &n| sum.checked_add(n))
+            .ok_or("the header counts are too large")?;
+        if inputs + ands > max {
+            return Err("M is smaller than I + A".to_string());
+        }
+        // check the header against the rest of the file before allocating anything for it
+        let rest = &data[reader.pos.min(data.len())..];
+        if binary {
+            // the inputs are implicit so every variable is an input or an `AND`, every output
+            // needs at least a digit and a newline and every `AND` two delta bytes
+            if max != inputs + ands {
+                return Err("M is not I + A".to_string());
+            }
+            if outputs + ands > rest.len() / 2 {
+                return Err("the file is shorter than the header says".to_string());
+            }
+        } else {
+            // every input, output and `AND` is a line of its own and a file can't have more
+            // unused variables than bytes
+            let lines = rest.split(|&c| c == b'\n').count();
+            if total > lines {
+                return Err("the file is shorter than the header says".to_string());
+            }
+            if max > data.len() {
+                return Err("M is larger than the file can describe".to_string());
+            }
+        }
+
+        // the definition of every variable
+        let len = max.checked_add(1).ok_or("M is too large")?;
+        let mut defs: Vec<Option<AigNode>> = vec![None; len];
+        defs[0] = Some(AigNode::Const);
+        for i in 0..inputs {
+            let var = if binary {
+                i + 1
+            } else {
+                let lit = reader.number()?;
+                if lit & 1 == 1 || lit == 0 {
+                    return Err(format!("invalid input literal {}", lit));
+                }
+                lit >> 1
+            };
+            define(&mut defs, var, AigNode::Input(i))?;
+        }
+        let mut output_lits = Vec::new();
+        for _ in 0..outputs {
+            output_lits.push(reader.number()?);
+        }
+        for i in 0..ands {
+            let (lhs, rhs0, rhs1) = if binary {
+                let lhs = (inputs + i + 1) << 1;
+                let rhs0 = lhs.checked_sub(reader.delta()?).ok_or("invalid delta")?;
+                let rhs1 = rhs0.checked_sub(reader.delta()?).ok_or("invalid delta")?;
+                (lhs, rhs0, rhs1)
+            } else {
+                let line = reader.line()?;
+                let lits = line
+                    .split_whitespace()
+                    .map(|field| {
+                        field
+                            .parse::<usize>()
+                            .map_err(|_| format!("invalid number {}", field))
+                    })
+                    .collect::<Result<Vec<usize>, String>>()?;
+                if lits.len() != 3 {
+                    return Err(format!("expected an and gate got {}", line));
+                }
+                (lits[0], lits[1], lits[2])
+            };
+            if lhs & 1 == 1 {
+                return Err(format!("invalid and literal {}", lhs));
+            }
+            define(
+                &mut defs,
+                lhs >> 1,
+                AigNode::And(AigLit(rhs0), AigLit(rhs1)),
+            )?;
+        }
+
+        // symbol table and comments
+        let mut input_names: Vec<String> = (0..inputs).map(|i| format!("i{}", i)).collect();
+        let mut output_names: Vec<String> = (0..outputs).map(|i| format!("o{}", i)).collect();
+        while reader.pos < data.len() {
+            let line = reader.line()?;
+            if line == "c" {
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            // the kind can be any character in a broken file so don't split in the middle of one
+            let mut chars = line.chars();
+            let kind = chars.next();
+            let mut parts = chars.as_str().splitn(2, ' ');
+            let position = parts.next().and_then(|p| p.parse::<usize>().ok());
+            let name = parts.next();
+            match (kind, position, name) {
+                (Some('i'), Some(p), Some(name)) if p < inputs => input_names[p] = name.to_string(),
+                (Some('o'), Some(p), Some(name)) if p < outputs => {
+                    output_names[p] = name.to_string()
+                }
+                (Some('l'), _, _)
+                | (Some('b'), _, _)
+                | (Some('c'), _, _)
+                | (Some('j'), _, _)
+                | (Some('f'), _, _) => (),
+                _ => return Err(format!("invalid symbol {}", line)),
+            }
+        }
+
+        let mut aig = Aig::new();
+        for name in &input_names {
+            aig.add_input(name)?;
+        }
+        let lits = aig.resolve(&defs)?;
+        for (name, lit) in output_names.iter().zip(output_lits) {
+            let var = lit >> 1;
+            match lits.get(var).copied().flatten() {
+                Some(resolved) => aig.add_output(name, AigLit(resolved.0 ^ (lit & 1)))?,
+                None => return Err(format!("undefined literal {}", lit)),
+            }
+        }
+        Ok(aig)
+    }
+
+    /// adds the `AND`s of the file and returns the edge of every variable
+    fn resolve(&mut self, defs: &[Option<AigNode>]) -> Result<Vec<Option<AigLit>>, String> {
+        let mut lits: Vec<Option<AigLit>> = vec![None; defs.len()];
+        let mut visiting = vec![false; defs.len()];
+
+        for root in 0..defs.len() {
+            if defs[root].is_none() {
+                continue;
+            }
+            // depth first without recursion so long chains don't overflow the stack
+            let mut stack = vec![root];
+            while let Some(&var) = stack.last() {
+                if lits[var].is_some() {
+                    stack.pop();
+                    continue;
+                }
+                match defs[var] {
+                    Some(AigNode::Const) => lits[var] = Some(AigLit::FALSE),
+                    Some(AigNode::Input(index)) => lits[var] = Some(AigLit::new(index + 1, false)),
+                    Some(AigNode::And(a, b)) => {
+                        let mut ready = true;
+                        for child in [a, b].iter() {
+                            let child = child.node();
+                            if child >= defs.len() || defs[child].is_none() {
+                                return Err(format!("undefined literal {}", child << 1));
+                            }
+                            if lits[child].is_none() {
+                                if visiting[child] {
+                                    return Err("the gates have a cycle".to_string());
+                                }
+                                ready = false;
+                                stack.push(child);
+                            }
+                        }
+                        if ready {
+                            let a = AigLit(lits[a.node()].unwrap().0 ^ (a.0 & 1));
+                            let b = AigLit(lits[b.node()].unwrap().0 ^ (b.0 & 1));
+                            lits[var] = Some(self.and(a, b));
+                        } else {
+                            visiting[var] = true;
+                        }
+                    }
+                    None => unreachable!("checked before pushing"),
+                }
+            }
+        }
+        Ok(lits)
+    }
+}
+
+fn define(defs: &mut [Option<AigNode>], var: usize, node: AigNode) -> Result<(), String> {
+    match defs.get_mut(var) {
+        Some(def @ None) => {
+            *def = Some(node);
+            Ok(())
+        }
+        Some(_) => Err(format!("variable {} is defined twice", var)),
+        None => Err(format!("variable {} is larger than M", var)),
+    }
+}
+
+fn write_delta(out: &mut Vec<u8>, mut delta: usize) {
+    while delta >= 0x80 {
+        out.push((delta & 0x7f) as u8 | 0x80);
+        delta >>= 7;
+    }
+    out.push(delta as u8);
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn line(&mut self) -> Result<&'a str, String> {
+        if self.pos >= self.data.len() {
+            return Err("unexpected end of file".to_string());
+        }
+        let rest = &self.data[self.pos..];
+        let len = rest.iter().position(|&c| c == b'\n').unwrap_or(rest.len());
+        self.pos += len + 1;
+        std::str::from_utf8(&rest[..len])
+            .map(|line| line.trim_end_matches('\r'))
+            .map_err(|_| "invalid utf-8".to_string())
+    }
+
+    fn number(&mut self) -> Result<usize, String> {
+        let line = self.line()?.trim();
+        line.parse::<usize>()
+            .map_err(|_| format!("invalid number {}", line))
+    }
+
+    fn delta(&mut self) -> Result<usize, String> {
+        let mut delta = 0;
+        let mut shift = 0;
+        loop {
+            let byte = *self.data.get(self.pos).ok_or("unexpected end of file")?;
+            self.pos += 1;
+            if shift >= usize::BITS {
+                return Err("invalid delta".to_string());
+            }
+            delta |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(delta);
+            }
+            shift += 7;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta() {
+        for &delta in [0, 1, 0x7f, 0x80, 0x3fff, 0x4000, 123_456_789].iter() {
+            let mut out = Vec::new();
+            write_delta(&mut out, delta);
+            let mut reader = Reader { data: &out, pos: 0 };
+            assert_eq!(reader.delta(), Ok(delta));
+            assert_eq!(reader.pos, out.len());
+        }
+
+        let mut out = Vec::new();
+        write_delta(&mut out, 0x80);
+        assert_eq!(out, vec![0x80, 0x01]);
+    }
+}
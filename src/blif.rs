@@ -0,0 +1,436 @@
+use crate::{compose, dnf, validate_tabel, Token};
+use std::collections::HashMap;
+
+/// a `.names` block of a `Blif` model: a single output function of some signals
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct BlifNode {
+    /// the input signals
+    pub inputs: Vec<String>,
+    /// the signal the node drives
+    pub output: String,
+    /// the compressed table over `inputs`
+    pub table: Vec<bool>,
+}
+
+impl BlifNode {
+    /// the function of the node in disjunctive normal form (see `dnf`)
+    pub fn func(&self) -> Result<Vec<Token>, String> {
+        validate_tabel(&self.table, &self.inputs)?;
+        if self.table.iter().all(|&b| !b) {
+            return Ok(vec![Token::Zero]);
+        }
+        if self.table.iter().all(|&b| b) {
+            return Ok(vec![Token::One]);
+        }
+        dnf(&self.table, &self.inputs, true)
+    }
+}
+
+/// a combinational model in the Berkeley Logic Interchange Format (BLIF) used by SIS and ABC
+///
+/// only `.model`, `.inputs`, `.outputs`, `.names` and `.end` are supported,
+/// the nodes don't have to be in topological order
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// let blif = Blif::from_blif("
+/// .model example
+/// .inputs a b c
+/// .outputs y
+/// .names a b t
+/// 11 1
+/// .names t c y
+/// 1- 1
+/// -1 1
+/// .end
+/// ").unwrap();
+///
+/// assert_eq!(blif.nodes[0].table, vec![false, false, false, true]);
+/// // a & b | c
+/// assert_eq!(
+///     blif.tables(),
+///     Ok(vec![vec![false, true, false, true, false, true, true, true]])
+/// );
+/// ```
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Blif {
+    pub model: String,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    pub nodes: Vec<BlifNode>,
+}
+
+impl Blif {
+    /// builds a model with a node for every output from the tables of a multi output function,
+    /// in the layout `parse_full` returns
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a half adder: a ⊕ b and a & b
+    /// let tables = parse_full(2, 2, vec![
+    ///     false, false, false, false,
+    ///     false, true, true, false,
+    ///     true, false, true, false,
+    ///     true, true, false, true,
+    /// ]).unwrap();
+    /// let inputs = vec!["a".to_string(), "b".to_string()];
+    /// let outputs = vec!["sum".to_string(), "carry".to_string()];
+    ///
+    /// let blif = Blif::from_tables("half_adder", &inputs, &outputs, &tables).unwrap();
+    /// assert_eq!(
+    ///     blif.to_blif(),
+    ///     ".model half_adder\n.inputs a b\n.outputs sum carry\n\
+    ///      .names a b sum\n01 1\n10 1\n\
+    ///      .names a b carry\n11 1\n.end\n"
+    /// );
+    /// ```
+    pub fn from_tables(
+        model: &str,
+        inputs: &[String],
+        outputs: &[String],
+        tables: &[Vec<bool>],
+    ) -> Result<Self, String> {
+        if outputs.len() != tables.len() {
+            return Err(format!(
+                "expected {} tables got {}",
+                outputs.len(),
+                tables.len()
+            ));
+        }
+        let mut nodes = Vec::new();
+        for (output, table) in outputs.iter().zip(tables) {
+            validate_tabel(table, inputs)?;
+            nodes.push(BlifNode {
+                inputs: inputs.to_vec(),
+                output: output.clone(),
+                table: table.clone(),
+            });
+        }
+        let blif = Self {
+            model: model.to_string(),
+            inputs: inputs.to_vec(),
+            outputs: outputs.to_vec(),
+            nodes,
+        };
+        blif.order()?;
+        Ok(blif)
+    }
+
+    /// reads the first model of a BLIF file
+    pub fn from_blif(text: &str) -> Result<Self, String> {
+        let mut model = None;
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+        let mut nodes = Vec::new();
+        // the signals and the cubes of the current `.names`
+        let mut current: Option<Vec<String>> = None;
+        let mut cubes = Vec::new();
+
+        for line in logical_lines(text) {
+            let mut fields = line.split_whitespace();
+            let first = match fields.next() {
+                Some(first) => first,
+                None => continue,
+            };
+
+            if !first.starts_with('.') {
+                if current.is_none() {
+                    return Err(format!("unexpected line {}", line));
+                }
+                let (cube, value) = match (fields.next(), fields.next()) {
+                    (Some(value), None) => (first, value),
+                    // a constant node only has the output column
+                    (None, None) => ("", first),
+                    _ => return Err(format!("invalid cube {}", line)),
+                };
+                match value {
+                    "0" | "1" => cubes.push((cube.to_string(), value == "1")),
+                    _ => return Err(format!("invalid output {}", value)),
+                }
+                continue;
+            }
+
+            if let Some(signals) = current.take() {
+                nodes.push(names_node(signals, &cubes)?);
+                cubes.clear();
+            }
+            match first {
+                ".model" => {
+                    if model.is_some() {
+                        break;
+                    }
+                    model = Some(fields.next().unwrap_or("").to_string());
+                }
+                ".inputs" => inputs.extend(fields.map(|field| field.to_string())),
+                ".outputs" => outputs.extend(fields.map(|field| field.to_string())),
+                ".names" => {
+                    let signals: Vec<String> = fields.map(|field| field.to_string()).collect();
+                    if signals.is_empty() {
+                        return Err(".names without output".to_string());
+                    }
+                    current = Some(signals);
+                }
+                ".end" => break,
+                command => return Err(format!("{} is not supported", command)),
+            }
+        }
+        if let Some(signals) = current {
+            nodes.push(names_node(signals, &cubes)?);
+        }
+
+        let blif = Self {
+            model: model.ok_or("missing .model")?,
+            inputs,
+            outputs,
+            nodes,
+        };
+        blif.order()?;
+        Ok(blif)
+    }
+
+    /// writes the model, every node is written as the list of its true rows
+    pub fn to_blif(&self) -> String {
+        let mut out = format!(".model {}\n", self.model);
+        out += &format!(".inputs {}\n", self.inputs.join(" "));
+        out += &format!(".outputs {}\n", self.outputs.join(" "));
+        for node in &self.nodes {
+            out += ".names";
+            for signal in node.inputs.iter().chain(std::iter::once(&node.output)) {
+                out += " ";
+                out += signal;
+            }
+            out += "\n";
+            let len = node.inputs.len();
+            for (row, &value) in node.table.iter().enumerate() {
+                if value {
+                    for i in 0..len {
+                        out += if row >> (len - 1 - i) & 1 == 1 {
+                            "1"
+                        } else {
+                            "0"
+                        };
+                    }
+                    out += if len == 0 { "1\n" } else { " 1\n" };
+                }
+            }
+        }
+        out + ".end\n"
+    }
+
+    /// the indices of the nodes in topological order
+    fn order(&self) -> Result<Vec<usize>, String> {
+        let mut drivers = HashMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            if self.inputs.contains(&node.output) || drivers.insert(&node.output, i).is_some() {
+                return Err(format!("{} is driven more than once", node.output));
+            }
+        }
+        for signal in self
+            .outputs
+            .iter()
+            .chain(self.nodes.iter().flat_map(|node| &node.inputs))
+        {
+            if !self.inputs.contains(signal) && !drivers.contains_key(signal) {
+                return Err(format!("{} is not driven", signal));
+            }
+        }
+
+        // 0 = not visited, 1 = visiting, 2 = done
+        let mut state = vec![0; self.nodes.len()];
+        let mut order = Vec::new();
+        for root in 0..self.nodes.len() {
+            let mut stack = vec![root];
+            while let Some(&i) = stack.last() {
+                if state[i] == 2 {
+                    stack.pop();
+                    continue;
+                }
+                state[i] = 1;
+                let mut ready = true;
+                for signal in &self.nodes[i].inputs {
+                    if let Some(&j) = drivers.get(signal) {
+                        match state[j] {
+                            0 => {
+                                ready = false;
+                                stack.push(j);
+                            }
+                            1 => return Err(format!("{} depends on itself", signal)),
+                            _ => (),
+                        }
+                    }
+                }
+                if ready {
+                    state[i] = 2;
+                    order.push(i);
+                    stack.pop();
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// the compressed table of every output over the inputs, in the same layout `parse_full` returns
+    pub fn tables(&self) -> Result<Vec<Vec<bool>>, String> {
+        let order = self.order()?;
+        let len = self.inputs.len();
+        if len >= 64 {
+            return Err(format!("too many inputs {}", len));
+        }
+
+        let mut tables = vec![Vec::new(); self.outputs.len()];
+        let mut values: HashMap<&str, bool> = HashMap::new();
+        for row in 0..(1_usize << len) {
+            for (i, input) in self.inputs.iter().enumerate() {
+                values.insert(input.as_str(), row >> (len - 1 - i) & 1 == 1);
+            }
+            for &i in &order {
+                let node = &self.nodes[i];
+                let index = node.inputs.iter().fold(0, |index, signal| {
+                    index << 1 | values[signal.as_str()] as usize
+                });
+                values.insert(node.output.as_str(), node.table[index]);
+            }
+            for (table, output) in tables.iter_mut().zip(&self.outputs) {
+                table.push(values[output.as_str()]);
+            }
+        }
+        Ok(tables)
+    }
+
+    /// the function of every output over the inputs,
+    /// the functions of the nodes (see `BlifNode::func`) are composed
+    pub fn funcs(&self) -> Result<Vec<Vec<Token>>, String> {
+        let mut funcs: HashMap<String, Vec<Token>> = HashMap::new();
+        for input in &self.inputs {
+            funcs.insert(input.clone(), vec![Token::Var(input.clone())]);
+        }
+        for i in self.order()? {
+            let node = &self.nodes[i];
+            let func = compose(&node.func()?, &funcs)?;
+            funcs.insert(node.output.clone(), func);
+        }
+        Ok(self
+            .outputs
+            .iter()
+            .map(|output| funcs[output].clone())
+            .collect())
+    }
+}
+
+/// the lines without comments, lines ending with `\` are joined with the next one
+fn logical_lines(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for line in text.lines() {
+        let line = match line.find('#') {
+            Some(index) => &line[..index],
+            None => line,
+        };
+        match line.trim_end().strip_suffix('\\') {
+            Some(rest) => {
+                current += rest;
+                current += " ";
+            }
+            None => {
+                current += line;
+                lines.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// builds the table of a `.names` block from its cubes
+fn names_node(mut signals: Vec<String>, cubes: &[(String, bool)]) -> Result<BlifNode, String> {
+    let output = signals.pop().unwrap();
+    let len = signals.len();
+    if len >= 32 {
+        return Err(format!("{} has too many inputs", output));
+    }
+
+    // the cubes either list the rows where the output is 1 or the rows where it's 0
+    let on_set = match cubes.first() {
+        Some((_, value)) => *value,
+        None => true,
+    };
+    if cubes.iter().any(|(_, value)| *value != on_set) {
+        return Err(format!("{} mixes 0 and 1 outputs", output));
+    }
+
+    let mut table = vec![!on_set; 1 << len];
+    for (cube, _) in cubes {
+        if cube.chars().count() != len {
+            return Err(format!("invalid cube {} for {}", cube, output));
+        }
+        for (row, value) in table.iter_mut().enumerate() {
+            let mut matches = true;
+            for (i, c) in cube.chars().enumerate() {
+                let bit = row >> (len - 1 - i) & 1 == 1;
+                matches &= match c {
+                    '0' => !bit,
+                    '1' => bit,
+                    '-' => true,
+                    _ => return Err(format!("invalid cube {} for {}", cube, output)),
+                };
+            }
+            if matches {
+                *value = on_set;
+            }
+        }
+    }
+
+    Ok(BlifNode {
+        inputs: signals,
+        output,
+        table,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logical_lines() {
+        assert_eq!(
+            logical_lines(".inputs a \\\n b # comment\n# only a comment\n.end"),
+            vec![
+                ".inputs a   b ".to_string(),
+                "".to_string(),
+                ".end".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_names_node() {
+        let signals = vec!["a".to_string(), "b".to_string(), "y".to_string()];
+        let node = names_node(signals.clone(), &[("1-".to_string(), true)]).unwrap();
+        assert_eq!(node.table, vec![false, false, true, true]);
+
+        let node = names_node(signals.clone(), &[("11".to_string(), false)]).unwrap();
+        assert_eq!(node.table, vec![true, true, true, false]);
+
+        assert!(names_node(signals.clone(), &[("1".to_string(), true)]).is_err());
+        assert!(names_node(signals.clone(), &[("1x".to_string(), true)]).is_err());
+        assert!(names_node(
+            signals,
+            &[("1-".to_string(), true), ("01".to_string(), false)]
+        )
+        .is_err());
+
+        let node = names_node(vec!["y".to_string()], &[]).unwrap();
+        assert_eq!(node.table, vec![false]);
+        let node = names_node(vec!["y".to_string()], &[("".to_string(), true)]).unwrap();
+        assert_eq!(node.table, vec![true]);
+    }
+}
@@ -0,0 +1,475 @@
+use crate::parser::Expr;
+use crate::{symmetric_func, threshold, Token};
+
+/// the most inputs a block can have, the tables have `2^inputs` rows
+const MAX_INPUTS: usize = 24;
+
+/// a generated building block with a function and a table for every output
+///
+/// numbers (the operands of an adder, the select inputs of a multiplexer, the sum of an adder, ...)
+/// are written with the most significant bit first like the rows of the tables: `a3 a2 a1 a0`,
+/// lists of single bits (the data inputs of a multiplexer, the outputs of a decoder, ...)
+/// start at index 0: `d0 d1 d2 d3`
+#[derive(PartialEq, Debug, Clone, Eq)]
+pub struct Block {
+    /// the names of the inputs in the order of the table
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    /// the function of every output (over the names of the inputs)
+    pub funcs: Vec<Vec<Token>>,
+    /// the compressed table of every output in the layout of `parse_full`
+    pub tables: Vec<Vec<bool>>,
+}
+
+impl Block {
+    /// the table with a row of inputs followed by outputs for every assignment,
+    /// the input of `parse_full`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::parity(3).unwrap();
+    /// assert_eq!(parse_full(3, 1, block.full_table()), Ok(block.tables.clone()));
+    /// ```
+    pub fn full_table(&self) -> Vec<bool> {
+        let len = self.inputs.len();
+        let mut table = Vec::new();
+        for row in 0..(1_usize << len) {
+            table.extend((0..len).map(|i| row >> (len - 1 - i) & 1 == 1));
+            table.extend(self.tables.iter().map(|output| output[row]));
+        }
+        table
+    }
+
+    /// an adder of two `n` bit numbers and a carry in where every carry is built from the one before
+    ///
+    /// the inputs are `a`, `b` and `cin`, the outputs are `cout` and the sum `s`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::ripple_carry_adder(2).unwrap();
+    /// assert_eq!(block.inputs, vec!["a1", "a0", "b1", "b0", "cin"]);
+    /// assert_eq!(block.outputs, vec!["cout", "s1", "s0"]);
+    ///
+    /// // 10 + 11 + 1 = 110
+    /// let row = 0b10111;
+    /// assert_eq!(
+    ///     block.tables.iter().map(|table| table[row]).collect::<Vec<bool>>(),
+    ///     vec![true, true, false]
+    /// );
+    /// ```
+    pub fn ripple_carry_adder(n: usize) -> Result<Block, String> {
+        let layout = adder_layout(n)?;
+        let mut carry = Expr::Var(layout.cin);
+        let mut sums = Vec::new();
+        for i in 0..n {
+            let (a, b) = (Expr::Var(layout.a(i)), Expr::Var(layout.b(i)));
+            let propagate = xor(a.clone(), b.clone());
+            sums.push(xor(propagate.clone(), carry.clone()));
+            carry = or(and(a, b), and(carry, propagate));
+        }
+        layout.block(carry, sums)
+    }
+
+    /// the same adder as `ripple_carry_adder` but every carry is a sum of products
+    /// of the generate (`a & b`) and propagate (`a ⊕ b`) signals of the bits below it
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let ripple = Block::ripple_carry_adder(3).unwrap();
+    /// let lookahead = Block::carry_lookahead_adder(3).unwrap();
+    /// assert_eq!(ripple.tables, lookahead.tables);
+    /// assert_ne!(ripple.funcs, lookahead.funcs);
+    /// ```
+    pub fn carry_lookahead_adder(n: usize) -> Result<Block, String> {
+        let layout = adder_layout(n)?;
+        let generate = |i: usize| and(Expr::Var(layout.a(i)), Expr::Var(layout.b(i)));
+        let propagate = |i: usize| xor(Expr::Var(layout.a(i)), Expr::Var(layout.b(i)));
+        // c(i) = g(i - 1) | p(i - 1) & g(i - 2) | ... | p(i - 1) & ... & p(0) & cin
+        let carry = |i: usize| {
+            let terms = (0..=i).rev().map(|j| {
+                let first = if j == 0 {
+                    Expr::Var(layout.cin)
+                } else {
+                    generate(j - 1)
+                };
+                chain(
+                    Token::And,
+                    (j..i).map(propagate).chain(Some(first)).collect(),
+                )
+            });
+            chain(Token::Or, terms.collect())
+        };
+        let sums = (0..n).map(|i| xor(propagate(i), carry(i))).collect();
+        layout.block(carry(n), sums)
+    }
+
+    /// compares two `n` bit numbers `a` and `b`, the outputs are `gt` (a > b), `eq` and `lt` (a < b)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::comparator(1).unwrap();
+    /// assert_eq!(block.outputs, vec!["gt", "eq", "lt"]);
+    /// assert_eq!(block.tables[0], vec![false, false, true, false]);
+    /// assert_eq!(block.tables[1], vec![true, false, false, true]);
+    /// ```
+    pub fn comparator(n: usize) -> Result<Block, String> {
+        check(n, |n| 2 * n)?;
+        let mut inputs = numbered("a", n);
+        inputs.extend(numbered("b", n));
+        let (a, b) = (
+            |i: usize| Expr::Var(n - 1 - i),
+            |i: usize| Expr::Var(2 * n - 1 - i),
+        );
+
+        let equal = |i: usize| Expr::Binary(Token::Eq, Box::new(a(i)), Box::new(b(i)));
+        // the bits above i are equal and bit i decides
+        let decides = |i: usize, greater: bool| {
+            let (x, y) = if greater { (a(i), b(i)) } else { (b(i), a(i)) };
+            let first = and(x, Expr::Not(Box::new(y)));
+            chain(
+                Token::And,
+                (i + 1..n).rev().map(equal).chain(Some(first)).collect(),
+            )
+        };
+        let funcs = vec![
+            chain(Token::Or, (0..n).rev().map(|i| decides(i, true)).collect()),
+            chain(Token::And, (0..n).rev().map(equal).collect()),
+            chain(Token::Or, (0..n).rev().map(|i| decides(i, false)).collect()),
+        ];
+
+        let funcs = to_funcs(&funcs, &inputs);
+        let outputs = names(&["gt", "eq", "lt"]);
+        build(inputs, outputs, funcs, |row| {
+            let (a, b) = (row >> n, row & ((1 << n) - 1));
+            match a.cmp(&b) {
+                std::cmp::Ordering::Greater => 0b100,
+                std::cmp::Ordering::Equal => 0b010,
+                std::cmp::Ordering::Less => 0b001,
+            }
+        })
+    }
+
+    /// selects one of `2^n` data inputs `d` with the `n` select inputs `s`, the output is `y`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::multiplexer(1).unwrap();
+    /// assert_eq!(block.inputs, vec!["s0", "d0", "d1"]);
+    /// // s0 = 1 selects d1
+    /// assert!(block.tables[0][0b101]);
+    /// assert!(!block.tables[0][0b110]);
+    /// ```
+    pub fn multiplexer(n: usize) -> Result<Block, String> {
+        check(n, |n| n + (1 << n))?;
+        let count = 1 << n;
+        let mut inputs = numbered("s", n);
+        inputs.extend((0..count).map(|i| format!("d{}", i)));
+
+        let terms = (0..count)
+            .map(|i| and(minterm(0, n, i), Expr::Var(n + i)))
+            .collect();
+        let funcs = to_funcs(&[chain(Token::Or, terms)], &inputs);
+
+        build(inputs, names(&["y"]), funcs, |row| {
+            let select = row >> count;
+            row >> (count - 1 - select) & 1
+        })
+    }
+
+    /// sets exactly the output `y` with the index of the `n` inputs `s`
+    ///
+    /// the `2^n` tables have `2^n` rows each so `n` is limited like a block with `2 * n` inputs
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::decoder(2).unwrap();
+    /// assert_eq!(block.outputs, vec!["y0", "y1", "y2", "y3"]);
+    /// assert_eq!(block.tables[2], vec![false, false, true, false]);
+    /// ```
+    pub fn decoder(n: usize) -> Result<Block, String> {
+        check(n, |n| 2 * n)?;
+        let count = 1_usize << n;
+        let inputs = numbered("s", n);
+        let outputs = (0..count).map(|i| format!("y{}", i)).collect();
+        let funcs: Vec<Expr> = (0..count).map(|i| minterm(0, n, i)).collect();
+        let funcs = to_funcs(&funcs, &inputs);
+        // output `i` is only true in row `i`
+        let tables = (0..count)
+            .map(|i| (0..count).map(|row| row == i).collect())
+            .collect();
+        Ok(Block {
+            inputs,
+            outputs,
+            funcs,
+            tables,
+        })
+    }
+
+    /// a priority encoder of `2^n` inputs `d`: the outputs `y` are the highest index of a true input
+    /// and `v` is true if any input is true
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::encoder(1).unwrap();
+    /// assert_eq!(block.inputs, vec!["d0", "d1"]);
+    /// assert_eq!(block.outputs, vec!["y0", "v"]);
+    /// assert_eq!(block.tables[0], vec![false, true, false, true]);
+    /// assert_eq!(block.tables[1], vec![false, true, true, true]);
+    /// ```
+    pub fn encoder(n: usize) -> Result<Block, String> {
+        check(n, |n| 1 << n)?;
+        let count = 1 << n;
+        let inputs: Vec<String> = (0..count).map(|i| format!("d{}", i)).collect();
+        let mut outputs = numbered("y", n);
+        outputs.push("v".to_string());
+
+        // d(i) is the highest true input
+        let highest = |i: usize| {
+            let above = (i + 1..count).map(|j| Expr::Not(Box::new(Expr::Var(j))));
+            chain(
+                Token::And,
+                std::iter::once(Expr::Var(i)).chain(above).collect(),
+            )
+        };
+        let mut funcs: Vec<Expr> = (0..n)
+            .rev()
+            .map(|bit| {
+                let terms = (0..count).filter(|i| i >> bit & 1 == 1).map(highest);
+                chain(Token::Or, terms.collect())
+            })
+            .collect();
+        funcs.push(chain(Token::Or, (0..count).map(Expr::Var).collect()));
+        let funcs = to_funcs(&funcs, &inputs);
+
+        build(inputs, outputs, funcs, |row| {
+            // d0 is the most significant bit of the row
+            match (0..count).rev().find(|i| row >> (count - 1 - i) & 1 == 1) {
+                Some(index) => index << 1 | 1,
+                None => 0,
+            }
+        })
+    }
+
+    /// true if an odd number of the `n` inputs `x` are true, the output is `p`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::parity(2).unwrap();
+    /// assert_eq!(block.funcs[0], vec![
+    ///     Token::Var("x0".to_string()),
+    ///     Token::Xor,
+    ///     Token::Var("x1".to_string()),
+    /// ]);
+    /// ```
+    pub fn parity(n: usize) -> Result<Block, String> {
+        check(n, |n| n)?;
+        let inputs: Vec<String> = (0..n).map(|i| format!("x{}", i)).collect();
+        let funcs = to_funcs(
+            &[chain(Token::Xor, (0..n).map(Expr::Var).collect())],
+            &inputs,
+        );
+        build(inputs, names(&["p"]), funcs, |row| {
+            row.count_ones() as usize & 1
+        })
+    }
+
+    /// true if more than half of the `n` inputs `x` are true, the output is `m`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::majority(3).unwrap();
+    /// assert_eq!(block.tables[0], vec![false, false, false, true, false, true, true, true]);
+    /// ```
+    pub fn majority(n: usize) -> Result<Block, String> {
+        check(n, |n| n)?;
+        let inputs: Vec<String> = (0..n).map(|i| format!("x{}", i)).collect();
+        let funcs = vec![threshold(&inputs, n / 2 + 1)?];
+        build(inputs, names(&["m"]), funcs, |row| {
+            usize::from(row.count_ones() as usize > n / 2)
+        })
+    }
+
+    /// the number of true inputs of the `n` inputs `x` as a binary number `c`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let block = Block::popcount(3).unwrap();
+    /// assert_eq!(block.outputs, vec!["c1", "c0"]);
+    /// // 3 ones -> 11
+    /// assert!(block.tables[0][0b111] && block.tables[1][0b111]);
+    /// ```
+    pub fn popcount(n: usize) -> Result<Block, String> {
+        check(n, |n| n)?;
+        let inputs: Vec<String> = (0..n).map(|i| format!("x{}", i)).collect();
+        let bits = (usize::BITS - n.leading_zeros()) as usize;
+
+        let mut funcs = Vec::new();
+        for bit in (0..bits).rev() {
+            let weights: Vec<usize> = (0..=n).filter(|w| w >> bit & 1 == 1).collect();
+            funcs.push(symmetric_func(&inputs, &weights)?);
+        }
+        build(inputs, numbered("c", bits), funcs, |row| {
+            row.count_ones() as usize
+        })
+    }
+}
+
+/// the indices of the inputs of an adder
+struct AdderLayout {
+    n: usize,
+    cin: usize,
+}
+
+impl AdderLayout {
+    fn a(&self, bit: usize) -> usize {
+        self.n - 1 - bit
+    }
+
+    fn b(&self, bit: usize) -> usize {
+        2 * self.n - 1 - bit
+    }
+
+    /// `sums` starts at the least significant bit
+    fn block(&self, carry: Expr, sums: Vec<Expr>) -> Result<Block, String> {
+        let n = self.n;
+        let mut inputs = numbered("a", n);
+        inputs.extend(numbered("b", n));
+        inputs.push("cin".to_string());
+        let mut outputs = vec!["cout".to_string()];
+        outputs.extend(numbered("s", n));
+
+        let funcs: Vec<Expr> = std::iter::once(carry)
+            .chain(sums.into_iter().rev())
+            .collect();
+        let funcs = to_funcs(&funcs, &inputs);
+        build(inputs, outputs, funcs, |row| {
+            let mask = (1 << n) - 1;
+            (row >> (n + 1)) + (row >> 1 & mask) + (row & 1)
+        })
+    }
+}
+
+fn adder_layout(n: usize) -> Result<AdderLayout, String> {
+    check(n, |n| 2 * n + 1)?;
+    Ok(AdderLayout { n, cin: 2 * n })
+}
+
+/// checks the number of bits and the number of inputs of the block
+fn check(n: usize, inputs: fn(usize) -> usize) -> Result<(), String> {
+    if n == 0 {
+        return Err("a block needs at least one bit".to_string());
+    }
+    if n > MAX_INPUTS || inputs(n) > MAX_INPUTS {
+        return Err(format!("too many inputs for {} bits", n));
+    }
+    Ok(())
+}
+
+/// builds the block, `value` returns the outputs of a row as a number (the first output is the most significant bit)
+fn build<F: Fn(usize) -> usize>(
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    funcs: Vec<Vec<Token>>,
+    value: F,
+) -> Result<Block, String> {
+    let len = outputs.len();
+    let mut tables = vec![Vec::with_capacity(1 << inputs.len()); len];
+    for row in 0..(1_usize << inputs.len()) {
+        let value = value(row);
+        for (i, table) in tables.iter_mut().enumerate() {
+            table.push(value >> (len - 1 - i) & 1 == 1);
+        }
+    }
+    Ok(Block {
+        inputs,
+        outputs,
+        funcs,
+        tables,
+    })
+}
+
+fn to_funcs(exprs: &[Expr], inputs: &[String]) -> Vec<Vec<Token>> {
+    exprs.iter().map(|expr| expr.to_tokens(inputs)).collect()
+}
+
+/// `prefix` with the bits of an `n` bit number, the most significant bit first
+fn numbered(prefix: &str, n: usize) -> Vec<String> {
+    (0..n).rev().map(|i| format!("{}{}", prefix, i)).collect()
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// the product of the `n` inputs starting at `start` that is true for the number `value`
+fn minterm(start: usize, n: usize, value: usize) -> Expr {
+    let literals = (0..n)
+        .map(|i| {
+            let var = Expr::Var(start + i);
+            if value >> (n - 1 - i) & 1 == 1 {
+                var
+            } else {
+                Expr::Not(Box::new(var))
+            }
+        })
+        .collect();
+    chain(Token::And, literals)
+}
+
+/// joins the trees with the operator as a balanced tree, so a long chain stays shallow
+fn chain(operator: Token, mut exprs: Vec<Expr>) -> Expr {
+    match exprs.len() {
+        0 => Expr::Const(operator == Token::And),
+        1 => exprs.remove(0),
+        len => {
+            let right = exprs.split_off(len / 2);
+            Expr::Binary(
+                operator.clone(),
+                Box::new(chain(operator.clone(), exprs)),
+                Box::new(chain(operator, right)),
+            )
+        }
+    }
+}
+
+fn and(left: Expr, right: Expr) -> Expr {
+    Expr::Binary(Token::And, Box::new(left), Box::new(right))
+}
+
+fn or(left: Expr, right: Expr) -> Expr {
+    Expr::Binary(Token::Or, Box::new(left), Box::new(right))
+}
+
+fn xor(left: Expr, right: Expr) -> Expr {
+    Expr::Binary(Token::Xor, Box::new(left), Box::new(right))
+}
@@ -0,0 +1,454 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, Token};
+use std::collections::HashMap;
+
+/// the type of a gate
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum GateKind {
+    And,
+    Or,
+    Xor,
+    Not,
+    Nand,
+    Nor,
+    Xnor,
+    Buf,
+}
+
+impl GateKind {
+    pub const ALL: [GateKind; 8] = [
+        GateKind::And,
+        GateKind::Or,
+        GateKind::Xor,
+        GateKind::Not,
+        GateKind::Nand,
+        GateKind::Nor,
+        GateKind::Xnor,
+        GateKind::Buf,
+    ];
+
+    /// `Not` and `Buf` have a single input, every other gate has at least two
+    pub fn is_unary(&self) -> bool {
+        matches!(self, GateKind::Not | GateKind::Buf)
+    }
+
+    /// evaluates the gate for 64 assignments at once, every bit is one assignment
+    pub fn eval_bits(&self, inputs: &[u64]) -> u64 {
+        let and = || inputs.iter().fold(!0, |acc, x| acc & x);
+        let or = || inputs.iter().fold(0, |acc, x| acc | x);
+        let xor = || inputs.iter().fold(0, |acc, x| acc ^ x);
+        match self {
+            GateKind::And => and(),
+            GateKind::Or => or(),
+            GateKind::Xor => xor(),
+            GateKind::Nand => !and(),
+            GateKind::Nor => !or(),
+            GateKind::Xnor => !xor(),
+            GateKind::Not => !inputs[0],
+            GateKind::Buf => inputs[0],
+        }
+    }
+
+    /// evaluates the gate for a single assignment
+    pub fn eval(&self, inputs: &[bool]) -> bool {
+        let bits: Vec<u64> = inputs.iter().map(|&b| b as u64).collect();
+        self.eval_bits(&bits) & 1 == 1
+    }
+}
+
+impl std::fmt::Display for GateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GateKind::And => write!(f, "AND"),
+            GateKind::Or => write!(f, "OR"),
+            GateKind::Xor => write!(f, "XOR"),
+            GateKind::Not => write!(f, "NOT"),
+            GateKind::Nand => write!(f, "NAND"),
+            GateKind::Nor => write!(f, "NOR"),
+            GateKind::Xnor => write!(f, "XNOR"),
+            GateKind::Buf => write!(f, "BUF"),
+        }
+    }
+}
+
+/// a wire of a circuit
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum Signal {
+    Const(bool),
+    /// the index of the input
+    Input(usize),
+    /// the index of the gate
+    Gate(usize),
+}
+
+/// a gate of a circuit, see `Circuit`
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: Vec<Signal>,
+}
+
+/// a gate level netlist: named inputs, named outputs and a directed acyclic graph of gates
+///
+/// the gates are stored in topological order (a gate only uses inputs and gates before it),
+/// a signal can be used by any number of gates and outputs (fan-out),
+/// adding a gate that already exists returns the existing one (structural hashing)
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a half adder
+/// let mut circuit = Circuit::new();
+/// let a = circuit.add_input("a").unwrap();
+/// let b = circuit.add_input("b").unwrap();
+/// let sum = circuit.add_gate(GateKind::Xor, &[a, b]).unwrap();
+/// let carry = circuit.add_gate(GateKind::And, &[a, b]).unwrap();
+/// circuit.add_output("sum", sum).unwrap();
+/// circuit.add_output("carry", carry).unwrap();
+///
+/// assert_eq!(circuit.simulate(&[true, true]), Ok(vec![false, true]));
+/// assert_eq!(
+///     circuit.tables(),
+///     Ok(vec![
+///         vec![false, true, true, false],
+///         vec![false, false, false, true],
+///     ])
+/// );
+/// ```
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub struct Circuit {
+    inputs: Vec<String>,
+    outputs: Vec<(String, Signal)>,
+    gates: Vec<Gate>,
+    hash: HashMap<Gate, usize>,
+}
+
+impl Circuit {
+    /// an empty circuit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// builds a circuit with an output for every function named `out0`, `out1`, ...
+    ///
+    /// see `from_named_funcs`
+    pub fn from_funcs(funcs: &[Vec<Token>]) -> Result<Self, String> {
+        let named: Vec<(String, Vec<Token>)> = funcs
+            .iter()
+            .enumerate()
+            .map(|(i, func)| (format!("out{}", i), func.clone()))
+            .collect();
+        Self::from_named_funcs(&named)
+    }
+
+    /// builds a circuit with an output for every function
+    ///
+    /// the inputs are the variables of all functions (sorted like `get_names`),
+    /// the operators are mapped to gates: `≡` is `XNOR`, `a → b` is `!a | b` and `a ← b` is `a | !b`,
+    /// a negated `AND`, `OR` or `XOR` becomes a `NAND`, `NOR` or `XNOR`,
+    /// constants are folded and subterms that appear in more than one function are only built once
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // !(a & b) and (a & b) | c
+    /// let funcs = vec![
+    ///     (
+    ///         "x".to_string(),
+    ///         vec![
+    ///             Token::Not,
+    ///             Token::Open,
+    ///             Token::Var("a".to_string()),
+    ///             Token::And,
+    ///             Token::Var("b".to_string()),
+    ///             Token::Close,
+    ///         ],
+    ///     ),
+    ///     (
+    ///         "y".to_string(),
+    ///         vec![
+    ///             Token::Var("a".to_string()),
+    ///             Token::And,
+    ///             Token::Var("b".to_string()),
+    ///             Token::Or,
+    ///             Token::Var("c".to_string()),
+    ///         ],
+    ///     ),
+    /// ];
+    ///
+    /// let circuit = Circuit::from_named_funcs(&funcs).unwrap();
+    /// assert_eq!(circuit.inputs(), &["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// // NAND(a, b), AND(a, b), OR(AND(a, b), c)
+    /// assert_eq!(circuit.gates().len(), 3);
+    /// ```
+    pub fn from_named_funcs(funcs: &[(String, Vec<Token>)]) -> Result<Self, String> {
+        let (names, exprs) = build_funcs(funcs)?;
+
+        let mut circuit = Self::new();
+        for name in &names {
+            circuit.add_input(name)?;
+        }
+        for ((name, _), expr) in funcs.iter().zip(exprs) {
+            let signal = circuit.add_expr(&expr);
+            circuit.add_output(name, signal)?;
+        }
+        Ok(circuit)
+    }
+
+    fn add_expr(&mut self, expr: &Expr) -> Signal {
+        match expr {
+            Expr::Const(value) => Signal::Const(*value),
+            Expr::Var(index) => Signal::Input(*index),
+            Expr::Not(left) => match &**left {
+                Expr::Binary(operator, a, b) if negated_kind(operator).is_some() => {
+                    let kind = negated_kind(operator).unwrap();
+                    let inputs = [self.add_expr(a), self.add_expr(b)];
+                    self.insert(kind, &inputs)
+                }
+                left => {
+                    let input = self.add_expr(left);
+                    self.insert(GateKind::Not, &[input])
+                }
+            },
+            Expr::Binary(operator, a, b) => {
+                let mut a = self.add_expr(a);
+                let mut b = self.add_expr(b);
+                let kind = match operator {
+                    Token::And => GateKind::And,
+                    Token::Or => GateKind::Or,
+                    Token::Xor => GateKind::Xor,
+                    Token::Eq => GateKind::Xnor,
+                    Token::Nand => GateKind::Nand,
+                    Token::Nor => GateKind::Nor,
+                    Token::ImplicAB => {
+                        a = self.insert(GateKind::Not, &[a]);
+                        GateKind::Or
+                    }
+                    Token::ImplicBA => {
+                        b = self.insert(GateKind::Not, &[b]);
+                        GateKind::Or
+                    }
+                    _ => unreachable!("not a binary operator"),
+                };
+                self.insert(kind, &[a, b])
+            }
+        }
+    }
+
+    /// adds an input and returns its signal
+    pub fn add_input(&mut self, name: &str) -> Result<Signal, String> {
+        if self.inputs.iter().any(|input| input == name) {
+            return Err(format!("duplicate input {}", name));
+        }
+        self.inputs.push(name.to_string());
+        Ok(Signal::Input(self.inputs.len() - 1))
+    }
+
+    /// adds a gate (or finds the same gate) and returns its output signal
+    ///
+    /// returns an error if the number of inputs doesn't fit the gate or an input doesn't exist
+    pub fn add_gate(&mut self, kind: GateKind, inputs: &[Signal]) -> Result<Signal, String> {
+        if kind.is_unary() && inputs.len() != 1 {
+            return Err(format!("{} expects 1 input got {}", kind, inputs.len()));
+        }
+        if !kind.is_unary() && inputs.len() < 2 {
+            return Err(format!(
+                "{} expects at least 2 inputs got {}",
+                kind,
+                inputs.len()
+            ));
+        }
+        for signal in inputs {
+            self.check(*signal)?;
+        }
+        Ok(self.insert(kind, inputs))
+    }
+
+    fn insert(&mut self, kind: GateKind, inputs: &[Signal]) -> Signal {
+        let mut inputs = inputs.to_vec();
+        // all gates with more than one input are commutative
+        inputs.sort_unstable();
+        let gate = Gate { kind, inputs };
+        if let Some(&index) = self.hash.get(&gate) {
+            return Signal::Gate(index);
+        }
+        self.gates.push(gate.clone());
+        self.hash.insert(gate, self.gates.len() - 1);
+        Signal::Gate(self.gates.len() - 1)
+    }
+
+    fn check(&self, signal: Signal) -> Result<(), String> {
+        match signal {
+            Signal::Input(index) if index >= self.inputs.len() => {
+                Err(format!("input {} doesn't exist", index))
+            }
+            Signal::Gate(index) if index >= self.gates.len() => {
+                Err(format!("gate {} doesn't exist", index))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// adds an output
+    pub fn add_output(&mut self, name: &str, signal: Signal) -> Result<(), String> {
+        if self.outputs.iter().any(|(output, _)| output == name) {
+            return Err(format!("duplicate output {}", name));
+        }
+        self.check(signal)?;
+        self.outputs.push((name.to_string(), signal));
+        Ok(())
+    }
+
+    /// the names of the inputs
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// the names and signals of the outputs
+    pub fn outputs(&self) -> &[(String, Signal)] {
+        &self.outputs
+    }
+
+    /// the gates in topological order
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// computes the outputs for a single assignment of the inputs
+    pub fn simulate(&self, inputs: &[bool]) -> Result<Vec<bool>, String> {
+        let bits: Vec<u64> = inputs.iter().map(|&b| if b { !0 } else { 0 }).collect();
+        Ok(self
+            .simulate_bits(&bits)?
+            .iter()
+            .map(|&bits| bits & 1 == 1)
+            .collect())
+    }
+
+    /// computes the outputs for 64 assignments at once, every bit is one assignment
+    pub fn simulate_bits(&self, inputs: &[u64]) -> Result<Vec<u64>, String> {
+        if inputs.len() != self.inputs.len() {
+            return Err(format!(
+                "expected {} values got {}",
+                self.inputs.len(),
+                inputs.len()
+            ));
+        }
+        let values = self.gate_values(inputs);
+        Ok(self
+            .outputs
+            .iter()
+            .map(|(_, signal)| value(*signal, inputs, &values))
+            .collect())
+    }
+
+    /// the values of all gates for 64 assignments
+    pub(crate) fn gate_values(&self, inputs: &[u64]) -> Vec<u64> {
+        let mut values: Vec<u64> = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let gate_inputs: Vec<u64> = gate
+                .inputs
+                .iter()
+                .map(|signal| value(*signal, inputs, &values))
+                .collect();
+            values.push(gate.kind.eval_bits(&gate_inputs));
+        }
+        values
+    }
+
+    /// the compressed table of every output, in the same layout `parse_full` returns
+    pub fn tables(&self) -> Result<Vec<Vec<bool>>, String> {
+        bit_tables(self.inputs.len(), self.outputs.len(), |inputs| {
+            self.simulate_bits(inputs)
+        })
+    }
+}
+
+/// builds the folded trees of all functions over the variables of all functions
+pub(crate) fn build_funcs(
+    funcs: &[(String, Vec<Token>)],
+) -> Result<(Vec<String>, Vec<Expr>), String> {
+    let mut all = Vec::new();
+    for (_, func) in funcs {
+        validate_func(func)?;
+        all.extend(func.iter().cloned());
+    }
+    let names = get_names(&all);
+
+    let mut exprs = Vec::new();
+    for (_, func) in funcs {
+        exprs.push(Expr::build(func, &names)?.fold());
+    }
+    Ok((names, exprs))
+}
+
+/// the value of every input for the rows `start..start + count` packed into bits
+pub(crate) fn row_bits(start: usize, count: usize, len: usize) -> Vec<u64> {
+    (0..len)
+        .map(|i| {
+            (0..count).fold(0, |bits, k| {
+                bits | ((((start + k) >> (len - 1 - i)) & 1) as u64) << k
+            })
+        })
+        .collect()
+}
+
+/// the tables of all outputs, `simulate` gets the inputs of 64 rows at a time (see `row_bits`)
+/// and returns the bits of every output
+pub(crate) fn bit_tables<F: Fn(&[u64]) -> Result<Vec<u64>, String>>(
+    len: usize,
+    outputs: usize,
+    simulate: F,
+) -> Result<Vec<Vec<bool>>, String> {
+    if len >= 64 {
+        return Err(format!("too many inputs {}", len));
+    }
+
+    let rows = 1_usize << len;
+    let mut tables = vec![Vec::with_capacity(rows); outputs];
+    for start in (0..rows).step_by(64) {
+        let count = usize::min(64, rows - start);
+        let inputs = row_bits(start, count, len);
+        for (table, bits) in tables.iter_mut().zip(simulate(&inputs)?) {
+            table.extend((0..count).map(|k| bits >> k & 1 == 1));
+        }
+    }
+    Ok(tables)
+}
+
+pub(crate) fn value(signal: Signal, inputs: &[u64], gates: &[u64]) -> u64 {
+    match signal {
+        Signal::Const(true) => !0,
+        Signal::Const(false) => 0,
+        Signal::Input(index) => inputs[index],
+        Signal::Gate(index) => gates[index],
+    }
+}
+
+/// the gate of a negated operator
+fn negated_kind(operator: &Token) -> Option<GateKind> {
+    match operator {
+        Token::And => Some(GateKind::Nand),
+        Token::Or => Some(GateKind::Nor),
+        Token::Xor => Some(GateKind::Xnor),
+        Token::Eq => Some(GateKind::Xor),
+        Token::Nand => Some(GateKind::And),
+        Token::Nor => Some(GateKind::Or),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_bits() {
+        // rows 00, 01, 10, 11
+        assert_eq!(row_bits(0, 4, 2), vec![0b1100, 0b1010]);
+        assert_eq!(row_bits(2, 2, 2), vec![0b11, 0b10]);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, Token};
+use std::collections::HashMap;
+
+/// a formula in conjunctive normal form with auxiliary variables, see `tseitin`
+///
+/// the variables are numbered from 1 like in the DIMACS format,
+/// variable `i + 1` is the input `names[i]` and every variable after the inputs is an auxiliary variable,
+/// a clause is a list of literals where `-v` is the negation of `v`
+#[derive(PartialEq, Debug, Clone, Eq)]
+pub struct Cnf {
+    /// the names of the input variables
+    pub names: Vec<String>,
+    /// the number of variables (inputs and auxiliary)
+    pub vars: usize,
+    /// the clauses, all of them have to be true
+    pub clauses: Vec<Vec<i64>>,
+    /// the subterm every auxiliary variable stands for
+    pub aux: HashMap<usize, Vec<Token>>,
+}
+
+impl Cnf {
+    /// checks if an assignment of all variables (index `v - 1` for variable `v`) satisfies every clause
+    pub fn is_satisfied(&self, assignment: &[bool]) -> Result<bool, String> {
+        if assignment.len() != self.vars {
+            return Err(format!(
+                "expected {} values got {}",
+                self.vars,
+                assignment.len()
+            ));
+        }
+        Ok(self.clauses.iter().all(|clause| {
+            clause
+                .iter()
+                .any(|&lit| assignment[lit.unsigned_abs() as usize - 1] == (lit > 0))
+        }))
+    }
+
+    /// formats the clauses in the DIMACS format used by most SAT solvers,
+    /// the names of the inputs are written as comments
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a | b
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::Or,
+    ///     Token::Var("b".to_string()),
+    /// ];
+    /// let cnf = tseitin(&func).unwrap();
+    /// assert_eq!(
+    ///     cnf.to_dimacs(),
+    ///     "c 1 a\nc 2 b\np cnf 3 4\n-3 1 2 0\n3 -1 0\n3 -2 0\n3 0\n"
+    /// );
+    /// ```
+    pub fn to_dimacs(&self) -> String {
+        let mut out = String::new();
+        for (i, name) in self.names.iter().enumerate() {
+            out += &format!("c {} {}\n", i + 1, name);
+        }
+        out += &format!("p cnf {} {}\n", self.vars, self.clauses.len());
+        for clause in &self.clauses {
+            for lit in clause {
+                out += &format!("{} ", lit);
+            }
+            out += "0\n";
+        }
+        out
+    }
+}
+
+/// the [Tseitin transformation]: an equisatisfiable CNF with an auxiliary variable for every operator
+///
+/// the size of the result grows linearly with the function, unlike the canonical CNF of the table,
+/// every auxiliary variable `z` of a subterm `a op b` gets the clauses for `z ↔ a op b`,
+/// negations don't need a variable, they just negate the literal,
+/// equal subterms share the same variable
+///
+/// the assignments of the inputs that satisfy the function are the ones that satisfy the CNF,
+/// the values of the auxiliary variables are determined by the inputs
+///
+/// [Tseitin transformation]:https://en.wikipedia.org/wiki/Tseytin_transformation
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let cnf = tseitin(&func).unwrap();
+/// assert_eq!(cnf.vars, 3);
+/// assert_eq!(cnf.aux[&3], func);
+/// assert_eq!(cnf.clauses, vec![vec![-3, 1], vec![-3, 2], vec![3, -1, -2], vec![3]]);
+/// ```
+pub fn tseitin(func: &[Token]) -> Result<Cnf, String> {
+    encode(func, false)
+}
+
+/// the [Plaisted–Greenbaum] encoding: the same as `tseitin` but subterms that only appear
+/// on one side of a negation only get the clauses for one direction of `z ↔ a op b`
+///
+/// this saves about half of the clauses, the assignments of the inputs that satisfy the function
+/// are still the ones that can be extended to satisfy the CNF
+/// but the auxiliary variables are not always determined by the inputs
+///
+/// [Plaisted–Greenbaum]:https://en.wikipedia.org/wiki/Tseytin_transformation
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let cnf = plaisted_greenbaum(&func).unwrap();
+/// assert_eq!(cnf.clauses, vec![vec![-3, 1], vec![-3, 2], vec![3]]);
+/// ```
+pub fn plaisted_greenbaum(func: &[Token]) -> Result<Cnf, String> {
+    encode(func, true)
+}
+
+fn encode(func: &[Token], polarity_aware: bool) -> Result<Cnf, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let expr = Expr::build(func, &names)?.fold();
+
+    let mut encoder = Encoder {
+        names: &names,
+        polarity: HashMap::new(),
+        polarity_aware,
+        lits: HashMap::new(),
+        cnf: Cnf {
+            names: names.clone(),
+            vars: names.len(),
+            clauses: Vec::new(),
+            aux: HashMap::new(),
+        },
+    };
+
+    match expr {
+        Expr::Const(true) => (),
+        // the empty clause can't be satisfied
+        Expr::Const(false) => encoder.cnf.clauses.push(Vec::new()),
+        expr => {
+            if polarity_aware {
+                encoder.collect(&expr, true, false);
+            }
+            let root = encoder.encode(&expr);
+            encoder.cnf.clauses.push(vec![root]);
+        }
+    }
+    Ok(encoder.cnf)
+}
+
+struct Encoder<'a> {
+    names: &'a [String],
+    // (positive, negative) polarity of every binary subterm
+    polarity: HashMap<Expr, (bool, bool)>,
+    polarity_aware: bool,
+    lits: HashMap<Expr, i64>,
+    cnf: Cnf,
+}
+
+impl Encoder<'_> {
+    /// marks the polarities a subterm appears with
+    fn collect(&mut self, expr: &Expr, positive: bool, negative: bool) {
+        match expr {
+            Expr::Not(left) => self.collect(left, negative, positive),
+            Expr::Binary(operator, left, right) => {
+                let entry = self.polarity.entry(expr.clone()).or_insert((false, false));
+                // only continue with the polarities that are new
+                let positive = positive && !entry.0;
+                let negative = negative && !entry.1;
+                if !positive && !negative {
+                    return;
+                }
+                entry.0 |= positive;
+                entry.1 |= negative;
+
+                let both = (positive || negative, positive || negative);
+                let same = (positive, negative);
+                let flipped = (negative, positive);
+                let (l, r) = match operator {
+                    Token::And | Token::Or => (same, same),
+                    Token::Nand | Token::Nor => (flipped, flipped),
+                    Token::ImplicAB => (flipped, same),
+                    Token::ImplicBA => (same, flipped),
+                    _ => (both, both),
+                };
+                self.collect(left, l.0, l.1);
+                self.collect(right, r.0, r.1);
+            }
+            _ => (),
+        }
+    }
+
+    /// returns the literal that is equivalent to (or implies) the subterm
+    fn encode(&mut self, expr: &Expr) -> i64 {
+        match expr {
+            Expr::Var(index) => *index as i64 + 1,
+            Expr::Not(left) => -self.encode(left),
+            Expr::Binary(operator, left, right) => {
+                if let Some(&lit) = self.lits.get(expr) {
+                    return lit;
+                }
+                let a = self.encode(left);
+                let b = self.encode(right);
+
+                self.cnf.vars += 1;
+                let z = self.cnf.vars as i64;
+                self.cnf
+                    .aux
+                    .insert(self.cnf.vars, expr.to_tokens(self.names));
+                self.lits.insert(expr.clone(), z);
+
+                let (positive, negative) = if self.polarity_aware {
+                    self.polarity[expr]
+                } else {
+                    (true, true)
+                };
+                let (implies, implied) = definition(operator, z, a, b);
+                if positive {
+                    self.cnf.clauses.extend(implies);
+                }
+                if negative {
+                    self.cnf.clauses.extend(implied);
+                }
+                z
+            }
+            Expr::Const(_) => unreachable!("constants are folded"),
+        }
+    }
+}
+
+/// the clauses for `z → a op b` and `a op b → z`
+#[allow(clippy::type_complexity)]
+fn definition(operator: &Token, z: i64, a: i64, b: i64) -> (Vec<Vec<i64>>, Vec<Vec<i64>>) {
+    match operator {
+        Token::And => (vec![vec![-z, a], vec![-z, b]], vec![vec![z, -a, -b]]),
+        Token::Or => (vec![vec![-z, a, b]], vec![vec![z, -a], vec![z, -b]]),
+        Token::Nand => (vec![vec![-z, -a, -b]], vec![vec![z, a], vec![z, b]]),
+        Token::Nor => (vec![vec![-z, -a], vec![-z, -b]], vec![vec![z, a, b]]),
+        Token::ImplicAB => (vec![vec![-z, -a, b]], vec![vec![z, a], vec![z, -b]]),
+        Token::ImplicBA => (vec![vec![-z, a, -b]], vec![vec![z, -a], vec![z, b]]),
+        Token::Xor => (
+            vec![vec![-z, a, b], vec![-z, -a, -b]],
+            vec![vec![z, -a, b], vec![z, a, -b]],
+        ),
+        Token::Eq => (
+            vec![vec![-z, -a, b], vec![-z, a, -b]],
+            vec![vec![z, a, b], vec![z, -a, -b]],
+        ),
+        _ => unreachable!("not a binary operator"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::apply;
+
+    #[test]
+    fn test_definition() {
+        let operators = [
+            Token::And,
+            Token::Or,
+            Token::Xor,
+            Token::Eq,
+            Token::ImplicAB,
+            Token::ImplicBA,
+            Token::Nand,
+            Token::Nor,
+        ];
+        let value =
+            |lit: i64, values: [bool; 3]| values[lit.unsigned_abs() as usize - 1] == (lit > 0);
+        let holds = |clauses: &[Vec<i64>], values| {
+            clauses
+                .iter()
+                .all(|clause| clause.iter().any(|&lit| value(lit, values)))
+        };
+
+        for operator in operators.iter() {
+            let (implies, implied) = definition(operator, 3, 1, 2);
+            for row in 0..8 {
+                let values = [row & 4 != 0, row & 2 != 0, row & 1 != 0];
+                let result = apply(operator, values[0], values[1]);
+                assert_eq!(holds(&implies, values), !values[2] || result);
+                assert_eq!(holds(&implied, values), values[2] || !result);
+            }
+        }
+    }
+}
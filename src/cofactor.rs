@@ -0,0 +1,187 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, validate_tabel, Token};
+
+/// replaces the variable `var` with a constant and simplifies the function
+///
+/// if the function doesn't contain `var` it is only simplified
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // (a | b) & !c  with b = 1 -> !c
+/// let func = vec![
+///     Token::Open,
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Var("b".to_string()),
+///     Token::Close,
+///     Token::And,
+///     Token::Not,
+///     Token::Var("c".to_string()),
+/// ];
+/// assert_eq!(
+///     restrict(&func, "b", true),
+///     Ok(vec![Token::Not, Token::Var("c".to_string())])
+/// );
+/// ```
+pub fn restrict(func: &[Token], var: &str, value: bool) -> Result<Vec<Token>, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let expr = Expr::build(func, &names)?;
+
+    match names.iter().position(|name| name == var) {
+        Some(index) => Ok(restrict_expr(&expr, index, value).to_tokens(&names)),
+        None => Ok(expr.fold().to_tokens(&names)),
+    }
+}
+
+/// returns both cofactors `(f(var = 0), f(var = 1))` of the function
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a ⊕ b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Xor,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(
+///     cofactors(&func, "a"),
+///     Ok((
+///         vec![Token::Var("b".to_string())],
+///         vec![Token::Not, Token::Var("b".to_string())]
+///     ))
+/// );
+/// ```
+pub fn cofactors(func: &[Token], var: &str) -> Result<(Vec<Token>, Vec<Token>), String> {
+    Ok((restrict(func, var, false)?, restrict(func, var, true)?))
+}
+
+/// generates the [Shannon expansion] `var & f(var = 1) | !var & f(var = 0)` of the function
+///
+/// [Shannon expansion]:https://en.wikipedia.org/wiki/Boole%27s_expansion_theorem
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a ⊕ b -> a & !b | !a & b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Xor,
+///     Token::Var("b".to_string()),
+/// ];
+/// let expansion = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Not,
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Not,
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(shannon_expand(&func, "a"), Ok(expansion));
+/// assert_eq!(parse(&shannon_expand(&func, "a").unwrap()), parse(&func));
+/// ```
+pub fn shannon_expand(func: &[Token], var: &str) -> Result<Vec<Token>, String> {
+    validate_func(func)?;
+    let mut names = get_names(func);
+    if !names.iter().any(|name| name == var) {
+        names.push(var.to_string());
+    }
+    let index = names.iter().position(|name| name == var).unwrap();
+    let expr = Expr::build(func, &names)?;
+
+    let expansion = Expr::Binary(
+        Token::Or,
+        Box::new(Expr::Binary(
+            Token::And,
+            Box::new(Expr::Var(index)),
+            Box::new(restrict_expr(&expr, index, true)),
+        )),
+        Box::new(Expr::Binary(
+            Token::And,
+            Box::new(Expr::Not(Box::new(Expr::Var(index)))),
+            Box::new(restrict_expr(&expr, index, false)),
+        )),
+    );
+    Ok(expansion.to_tokens(&names))
+}
+
+/// replaces the variable at `index` with a constant and simplifies the tree
+pub(crate) fn restrict_expr(expr: &Expr, index: usize, value: bool) -> Expr {
+    expr.map_vars(&|i| {
+        if i == index {
+            Expr::Const(value)
+        } else {
+            Expr::Var(i)
+        }
+    })
+    .fold()
+}
+
+/// returns the half of a compressed table where `var` has the given value
+///
+/// the result is a table over all names except `var` in the same order
+///
+/// ## Example
+///
+/// ```rust
+/// // a & !b -> 0010
+/// let table = vec![false, false, true, false];
+/// let names = vec!["a".to_string(), "b".to_string()];
+///
+/// assert_eq!(bool_algebra::restrict_table(&table, &names, "a", true), Ok(vec![true, false]));
+/// assert_eq!(bool_algebra::restrict_table(&table, &names, "b", false), Ok(vec![false, true]));
+/// ```
+pub fn restrict_table(
+    table: &[bool],
+    names: &[String],
+    var: &str,
+    value: bool,
+) -> Result<Vec<bool>, String> {
+    validate_tabel(table, names)?;
+    let position = match names.iter().position(|name| name == var) {
+        Some(position) => position,
+        None => return Err(format!("variable {} is not in the table", var)),
+    };
+
+    let mask = 1 << (names.len() - 1 - position);
+    Ok((0..table.len())
+        .filter(|i| (i & mask != 0) == value)
+        .map(|i| table[i])
+        .collect())
+}
+
+/// returns both cofactors `(f(var = 0), f(var = 1))` of a compressed table, see `restrict_table`
+///
+/// ## Example
+///
+/// ```rust
+/// // a ⊕ b -> 0110
+/// let table = vec![false, true, true, false];
+/// let names = vec!["a".to_string(), "b".to_string()];
+///
+/// assert_eq!(
+///     bool_algebra::table_cofactors(&table, &names, "b"),
+///     Ok((vec![false, true], vec![true, false]))
+/// );
+/// ```
+pub fn table_cofactors(
+    table: &[bool],
+    names: &[String],
+    var: &str,
+) -> Result<(Vec<bool>, Vec<bool>), String> {
+    Ok((
+        restrict_table(table, names, var, false)?,
+        restrict_table(table, names, var, true)?,
+    ))
+}
@@ -0,0 +1,372 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, Token};
+
+/// a single instruction of the stack machine
+///
+/// the stack is a `u64` where every bit is one entry, the top of the stack is the lowest bit
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+enum Op {
+    Const(bool),
+    Var(usize),
+    Not,
+    Xor,
+    Eq,
+    // if the top is false jump to the index and keep it otherwise pop it
+    JumpIfFalse(usize),
+    // if the top is true jump to the index and keep it otherwise pop it
+    JumpIfTrue(usize),
+}
+
+/// a function compiled to a flat list of stack machine instructions
+///
+/// `and`, `or`, `nand`, `nor` and the implications are short-circuited with jumps,
+/// so the evaluation only does the work it needs and never allocates
+///
+/// the values can either be passed as a slice in the order of `names()`
+/// or as a bitmask where the first name is the most significant bit (the row index of the table `parse` generates)
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // (a | b) & !c
+/// let func = vec![
+///     Token::Open,
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Var("b".to_string()),
+///     Token::Close,
+///     Token::And,
+///     Token::Not,
+///     Token::Var("c".to_string()),
+/// ];
+/// let compiled = CompiledExpr::new(&func).unwrap();
+///
+/// assert!(compiled.eval(&[false, true, false]));
+/// assert!(!compiled.eval(&[false, true, true]));
+///
+/// // 0b010 -> a = 0, b = 1, c = 0
+/// assert!(compiled.eval_bits(0b010));
+/// assert!(!compiled.eval_bits(0b011));
+/// ```
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct CompiledExpr {
+    names: Vec<String>,
+    code: Vec<Op>,
+}
+
+impl CompiledExpr {
+    /// compiles the function with the variables in the order of `get_names`
+    pub fn new(func: &[Token]) -> Result<Self, String> {
+        Self::with_order(func, &get_names(func))
+    }
+
+    /// compiles the function with the variables in the order of `names`
+    /// `names` must contain every variable of the function but can contain more
+    pub fn with_order(func: &[Token], names: &[String]) -> Result<Self, String> {
+        validate_func(func)?;
+        let expr = Expr::build(func, names)?;
+        let measured = measure(&expr);
+        if measured.depth > u64::BITS as usize {
+            return Err("expression is too deeply nested".to_string());
+        }
+
+        let mut code = Vec::with_capacity(measured.size);
+        emit(&measured, &mut code);
+        Ok(Self {
+            names: names.to_vec(),
+            code,
+        })
+    }
+
+    /// the names of the variables in the order the values are expected
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// evaluates the function, `values` are in the order of `names()`
+    ///
+    /// panics if there are fewer values than names
+    pub fn eval(&self, values: &[bool]) -> bool {
+        self.run(|index| values[index])
+    }
+
+    /// evaluates the function, the first name is the most significant bit of `bits`
+    ///
+    /// panics if there are more than 64 names
+    pub fn eval_bits(&self, bits: u64) -> bool {
+        assert!(
+            self.names.len() <= u64::BITS as usize,
+            "eval_bits needs at most 64 names got {}",
+            self.names.len()
+        );
+        let last = self.names.len().saturating_sub(1);
+        self.run(|index| bits >> (last - index) & 1 == 1)
+    }
+
+    fn run<F: Fn(usize) -> bool>(&self, value: F) -> bool {
+        let mut stack = 0_u64;
+        let mut pc = 0;
+
+        while pc < self.code.len() {
+            match self.code[pc] {
+                Op::Const(b) => stack = stack << 1 | b as u64,
+                Op::Var(index) => stack = stack << 1 | value(index) as u64,
+                Op::Not => stack ^= 1,
+                Op::Xor => stack = (stack >> 1) ^ (stack & 1),
+                Op::Eq => stack = (stack >> 1) ^ (stack & 1) ^ 1,
+                Op::JumpIfFalse(target) => {
+                    if stack & 1 == 0 {
+                        pc = target;
+                        continue;
+                    }
+                    stack >>= 1;
+                }
+                Op::JumpIfTrue(target) => {
+                    if stack & 1 == 1 {
+                        pc = target;
+                        continue;
+                    }
+                    stack >>= 1;
+                }
+            }
+            pc += 1;
+        }
+
+        stack & 1 == 1
+    }
+}
+
+/// the expression with the stack depth and the number of instructions of every subtree,
+/// computed once so `emit` doesn't have to walk the subtrees again
+struct Measured<'a> {
+    expr: &'a Expr,
+    /// number of stack entries needed to evaluate the expression with `emit`
+    depth: usize,
+    /// exact number of instructions `emit` generates, the smaller side of and / or gets checked first
+    size: usize,
+    children: Vec<Measured<'a>>,
+}
+
+fn measure(expr: &Expr) -> Measured<'_> {
+    let children: Vec<Measured> = match expr {
+        Expr::Const(_) | Expr::Var(_) => Vec::new(),
+        Expr::Not(left) => vec![measure(left)],
+        Expr::Binary(_, left, right) => vec![measure(left), measure(right)],
+    };
+    let (depth, size) = match (expr, &children[..]) {
+        (Expr::Not(_), [left]) => (left.depth, left.size + 1),
+        (Expr::Binary(operator, _, _), [left, right]) => {
+            let depth = match operator {
+                // the deeper side is evaluated first
+                Token::Xor | Token::Eq if left.depth == right.depth => left.depth + 1,
+                // short-circuited operators pop the first result before the second is evaluated
+                _ => usize::max(left.depth, right.depth),
+            };
+            // the jump of the short-circuited operators and the `Not` of the negated ones
+            let own = match operator {
+                Token::Xor | Token::Eq | Token::And | Token::Or => 1,
+                _ => 2,
+            };
+            (depth, left.size + right.size + own)
+        }
+        _ => (1, 1),
+    };
+    Measured {
+        expr,
+        depth,
+        size,
+        children,
+    }
+}
+
+fn emit(measured: &Measured, code: &mut Vec<Op>) {
+    match (measured.expr, &measured.children[..]) {
+        (Expr::Const(b), _) => code.push(Op::Const(*b)),
+        (Expr::Var(index), _) => code.push(Op::Var(*index)),
+        (Expr::Not(_), [left]) => {
+            emit(left, code);
+            code.push(Op::Not);
+        }
+        (Expr::Binary(operator, _, _), [left, right]) => match operator {
+            Token::Xor | Token::Eq => {
+                // both are commutative so evaluate the deeper side first to keep the stack small
+                if left.depth >= right.depth {
+                    emit(left, code);
+                    emit(right, code);
+                } else {
+                    emit(right, code);
+                    emit(left, code);
+                }
+                code.push(if operator == &Token::Xor {
+                    Op::Xor
+                } else {
+                    Op::Eq
+                });
+            }
+            Token::And => emit_short_circuit(left, false, right, false, false, code),
+            Token::Or => emit_short_circuit(left, false, right, false, true, code),
+            Token::Nand => {
+                emit_short_circuit(left, false, right, false, false, code);
+                code.push(Op::Not);
+            }
+            Token::Nor => {
+                emit_short_circuit(left, false, right, false, true, code);
+                code.push(Op::Not);
+            }
+            // a → b = !a | b
+            Token::ImplicAB => emit_short_circuit(left, true, right, false, true, code),
+            // a ← b = a | !b
+            Token::ImplicBA => emit_short_circuit(left, false, right, true, true, code),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// emits `left and right` or `left or right` (`is_or`), the sides can be negated
+fn emit_short_circuit(
+    left: &Measured,
+    not_left: bool,
+    right: &Measured,
+    not_right: bool,
+    is_or: bool,
+    code: &mut Vec<Op>,
+) {
+    let (first, not_first, second, not_second) = if left.size <= right.size {
+        (left, not_left, right, not_right)
+    } else {
+        (right, not_right, left, not_left)
+    };
+
+    emit(first, code);
+    if not_first {
+        code.push(Op::Not);
+    }
+    let jump = code.len();
+    code.push(Op::Const(false)); // placeholder for the jump
+    emit(second, code);
+    if not_second {
+        code.push(Op::Not);
+    }
+
+    let target = code.len();
+    code[jump] = if is_or {
+        Op::JumpIfTrue(target)
+    } else {
+        Op::JumpIfFalse(target)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> Token {
+        Token::Var(name.to_string())
+    }
+
+    #[test]
+    fn test_emit_and() {
+        let compiled = CompiledExpr::new(&[var("a"), Token::And, var("b")]).unwrap();
+        assert_eq!(
+            compiled.code,
+            vec![Op::Var(0), Op::JumpIfFalse(3), Op::Var(1)]
+        );
+    }
+
+    #[test]
+    fn test_emit_smaller_first() {
+        // (a ⊕ b) | c -> c is checked first
+        let compiled = CompiledExpr::new(&[
+            Token::Open,
+            var("a"),
+            Token::Xor,
+            var("b"),
+            Token::Close,
+            Token::Or,
+            var("c"),
+        ])
+        .unwrap();
+        assert_eq!(
+            compiled.code,
+            vec![
+                Op::Var(2),
+                Op::JumpIfTrue(5),
+                Op::Var(0),
+                Op::Var(1),
+                Op::Xor
+            ]
+        );
+    }
+
+    #[test]
+    fn test_depth() {
+        // a ⊕ (b ⊕ (c ⊕ d)) only needs 2 entries because the deep side is evaluated first
+        let func = vec![
+            var("a"),
+            Token::Xor,
+            Token::Open,
+            var("b"),
+            Token::Xor,
+            Token::Open,
+            var("c"),
+            Token::Xor,
+            var("d"),
+            Token::Close,
+            Token::Close,
+        ];
+        let names = get_names(&func);
+        assert_eq!(measure(&Expr::build(&func, &names).unwrap()).depth, 2);
+
+        // (a ⊕ b) ⊕ (c ⊕ d)
+        let func = vec![
+            Token::Open,
+            var("a"),
+            Token::Xor,
+            var("b"),
+            Token::Close,
+            Token::Xor,
+            Token::Open,
+            var("c"),
+            Token::Xor,
+            var("d"),
+            Token::Close,
+        ];
+        assert_eq!(measure(&Expr::build(&func, &names).unwrap()).depth, 3);
+    }
+
+    #[test]
+    fn test_size() {
+        let operators = [
+            Token::And,
+            Token::Or,
+            Token::Xor,
+            Token::Eq,
+            Token::Nand,
+            Token::Nor,
+            Token::ImplicAB,
+            Token::ImplicBA,
+        ];
+        for operator in operators.iter() {
+            // !a <op> (b ⊕ c)
+            let func = vec![
+                Token::Not,
+                var("a"),
+                operator.clone(),
+                Token::Open,
+                var("b"),
+                Token::Xor,
+                var("c"),
+                Token::Close,
+            ];
+            let names = get_names(&func);
+            let expr = Expr::build(&func, &names).unwrap();
+            let mut code = Vec::new();
+            let measured = measure(&expr);
+            emit(&measured, &mut code);
+            assert_eq!(measured.size, code.len(), "{:?}", operator);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{table_vars, Token};
+
+/// the five maximal clones of [Post's lattice]
+///
+/// a set of operators is functionally complete if and only if for every class
+/// there is at least one operator that is not a member of it
+///
+/// [Post's lattice]:https://en.wikipedia.org/wiki/Post%27s_lattice
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+pub enum PostClass {
+    T0,       // preserves 0: f(0, .., 0) = 0
+    T1,       // preserves 1: f(1, .., 1) = 1
+    SelfDual, // !f(a, b) = f(!a, !b)
+    Monotone, // a <= b -> f(a) <= f(b)
+    Linear,   // f is a xor of variables and constants
+}
+
+impl PostClass {
+    /// all classes in the order they get reported
+    pub const ALL: [PostClass; 5] = [
+        PostClass::T0,
+        PostClass::T1,
+        PostClass::SelfDual,
+        PostClass::Monotone,
+        PostClass::Linear,
+    ];
+}
+
+impl std::fmt::Display for PostClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PostClass::T0 => write!(f, "T0"),
+            PostClass::T1 => write!(f, "T1"),
+            PostClass::SelfDual => write!(f, "self-dual"),
+            PostClass::Monotone => write!(f, "monotone"),
+            PostClass::Linear => write!(f, "linear"),
+        }
+    }
+}
+
+/// returns the compressed table of a single operator
+///
+/// binary operators have the table `a op b`, `Token::Not` is `!a` and the constants are 0-ary
+/// `Token::Open`, `Token::Close` and `Token::Var` are not operators and return `None`
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+/// assert_eq!(operator_table(&Token::ImplicAB), Some(vec![true, true, false, true]));
+/// assert_eq!(operator_table(&Token::One), Some(vec![true]));
+/// assert_eq!(operator_table(&Token::Open), None);
+/// ```
+pub fn operator_table(op: &Token) -> Option<Vec<bool>> {
+    let table = match op {
+        Token::And => vec![false, false, false, true],
+        Token::Or => vec![false, true, true, true],
+        Token::Xor => vec![false, true, true, false],
+        Token::Eq => vec![true, false, false, true],
+        Token::ImplicAB => vec![true, true, false, true],
+        Token::ImplicBA => vec![true, false, true, true],
+        Token::Nand => vec![true, true, true, false],
+        Token::Nor => vec![true, false, false, false],
+        Token::Not => vec![true, false],
+        Token::One => vec![true],
+        Token::Zero => vec![false],
+        Token::Open | Token::Close | Token::Var(_) => return None,
+    };
+    Some(table)
+}
+
+/// returns all classes of Post's lattice the function (given as compressed table) is a member of
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a ⊕ b
+/// let table = vec![false, true, true, false];
+/// assert_eq!(classify(&table), Ok(vec![PostClass::T0, PostClass::Linear]));
+/// ```
+pub fn classify(table: &[bool]) -> Result<Vec<PostClass>, String> {
+    let len = table_vars(table)?;
+    Ok(PostClass::ALL
+        .iter()
+        .filter(|&&class| is_member(table, len, class))
+        .cloned()
+        .collect())
+}
+
+/// checks if a set of operators is functionally complete
+///
+/// returns `Ok(())` if every boolean function can be built from the operators
+/// otherwise it returns `Err` with every class that contains all operators,
+/// to be complete the set needs at least one operator outside each of these classes
+///
+/// `Token::Open`, `Token::Close` and `Token::Var` are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// assert_eq!(is_functionally_complete(&[Token::ImplicAB, Token::Zero]), Ok(()));
+/// assert_eq!(is_functionally_complete(&[Token::Nand]), Ok(()));
+/// assert_eq!(
+///     is_functionally_complete(&[Token::Xor, Token::One]),
+///     Err(vec![PostClass::Linear])
+/// );
+/// ```
+pub fn is_functionally_complete(ops: &[Token]) -> Result<(), Vec<PostClass>> {
+    let tables: Vec<Vec<bool>> = ops.iter().filter_map(operator_table).collect();
+
+    let missing: Vec<PostClass> = PostClass::ALL
+        .iter()
+        .filter(|&&class| {
+            tables
+                .iter()
+                .all(|table| is_member(table, table_vars(table).unwrap(), class))
+        })
+        .cloned()
+        .collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(missing)
+    }
+}
+
+fn is_member(table: &[bool], len: usize, class: PostClass) -> bool {
+    match class {
+        PostClass::T0 => !table[0],
+        PostClass::T1 => table[table.len() - 1],
+        PostClass::SelfDual => is_self_dual(table),
+        PostClass::Monotone => is_monotone(table, len),
+        PostClass::Linear => is_linear(table, len),
+    }
+}
+
+/// the row with all inputs negated is the mirrored index
+fn is_self_dual(table: &[bool]) -> bool {
+    let last = table.len() - 1;
+    (0..table.len()).all(|i| table[i] != table[last - i])
+}
+
+/// setting any input from 0 to 1 must never change the result from 1 to 0
+fn is_monotone(table: &[bool], len: usize) -> bool {
+    for i in 0..table.len() {
+        for bit in 0..len {
+            let j = i | (1 << bit);
+            if j != i && table[i] && !table[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// computes the [algebraic normal form] and checks that no monomial has more than one variable
+///
+/// [algebraic normal form]:https://en.wikipedia.org/wiki/Algebraic_normal_form
+fn is_linear(table: &[bool], len: usize) -> bool {
+    anf(table, len)
+        .iter()
+        .enumerate()
+        .all(|(monomial, &coefficient)| !coefficient || monomial.count_ones() <= 1)
+}
+
+/// möbius transform: the coefficient at index `i` belongs to the monomial of all variables set in `i`
+fn anf(table: &[bool], len: usize) -> Vec<bool> {
+    let mut coefficients = table.to_vec();
+    for bit in 0..len {
+        for i in 0..coefficients.len() {
+            if i & (1 << bit) != 0 {
+                coefficients[i] ^= coefficients[i ^ (1 << bit)];
+            }
+        }
+    }
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anf() {
+        // a ⊕ b -> b + a
+        assert_eq!(
+            anf(&[false, true, true, false], 2),
+            vec![false, true, true, false]
+        );
+        // a ∧ b -> ab
+        assert_eq!(
+            anf(&[false, false, false, true], 2),
+            vec![false, false, false, true]
+        );
+        // a ∨ b -> b + a + ab
+        assert_eq!(
+            anf(&[false, true, true, true], 2),
+            vec![false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn test_is_monotone() {
+        assert!(is_monotone(&[false, true, true, true], 2));
+        assert!(!is_monotone(&[false, true, true, false], 2));
+        assert!(!is_monotone(&[true, false], 1));
+    }
+
+    #[test]
+    fn test_is_self_dual() {
+        assert!(is_self_dual(&[true, false]));
+        assert!(is_self_dual(&[false, true]));
+        assert!(!is_self_dual(&[false, false, false, true]));
+    }
+}
@@ -0,0 +1,653 @@
+use crate::parser::Expr;
+use crate::{validate_func, Token};
+
+/// the value of a variable in a cube
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+pub enum Literal {
+    /// the variable is 0 (`!x`)
+    Zero,
+    /// the variable is 1 (`x`)
+    One,
+    /// the variable can have any value (`-`)
+    DontCare,
+}
+
+/// a product term as a value for every variable, written like `10-` (`a & !b` over `a`, `b`, `c`)
+///
+/// the operations of two cubes panic if the cubes have a different number of variables
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Cube {
+    literals: Vec<Literal>,
+}
+
+impl Cube {
+    pub fn new(literals: Vec<Literal>) -> Self {
+        Self { literals }
+    }
+
+    /// the cube that covers every row
+    pub fn universe(vars: usize) -> Self {
+        Self::new(vec![Literal::DontCare; vars])
+    }
+
+    /// the cube that only covers the row (in the order of the table)
+    pub fn minterm(row: usize, vars: usize) -> Self {
+        Self::new(
+            (0..vars)
+                .map(|i| match row >> (vars - 1 - i) & 1 {
+                    1 => Literal::One,
+                    _ => Literal::Zero,
+                })
+                .collect(),
+        )
+    }
+
+    pub fn literals(&self) -> &[Literal] {
+        &self.literals
+    }
+
+    /// the number of variables
+    pub fn vars(&self) -> usize {
+        self.literals.len()
+    }
+
+    /// the number of variables that are not `-`
+    pub fn literal_count(&self) -> usize {
+        self.literals
+            .iter()
+            .filter(|&&literal| literal != Literal::DontCare)
+            .count()
+    }
+
+    /// the row (in the order of the table) is covered by the cube
+    pub fn covers(&self, row: usize) -> bool {
+        let vars = self.vars();
+        self.literals
+            .iter()
+            .enumerate()
+            .all(|(i, literal)| match literal {
+                Literal::DontCare => true,
+                Literal::One => row >> (vars - 1 - i) & 1 == 1,
+                Literal::Zero => row >> (vars - 1 - i) & 1 == 0,
+            })
+    }
+
+    /// every row of `other` is a row of `self`
+    pub fn contains(&self, other: &Cube) -> bool {
+        self.pairs(other)
+            .all(|(a, b)| a == Literal::DontCare || a == b)
+    }
+
+    /// the number of variables that are 0 in one cube and 1 in the other
+    pub fn distance(&self, other: &Cube) -> usize {
+        self.pairs(other).filter(|&(a, b)| opposite(a, b)).count()
+    }
+
+    /// the rows that are in both cubes (`None` if there are none)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let a: Cube = "1--".parse().unwrap();
+    /// let b: Cube = "-0-".parse().unwrap();
+    /// assert_eq!(a.intersect(&b), Some("10-".parse().unwrap()));
+    /// assert_eq!(a.intersect(&"0--".parse().unwrap()), None);
+    /// ```
+    pub fn intersect(&self, other: &Cube) -> Option<Cube> {
+        if self.distance(other) > 0 {
+            return None;
+        }
+        Some(Cube::new(
+            self.pairs(other)
+                .map(|(a, b)| if a == Literal::DontCare { b } else { a })
+                .collect(),
+        ))
+    }
+
+    /// the smallest cube that contains both cubes
+    pub fn supercube(&self, other: &Cube) -> Cube {
+        Cube::new(
+            self.pairs(other)
+                .map(|(a, b)| if a == b { a } else { Literal::DontCare })
+                .collect(),
+        )
+    }
+
+    /// the consensus of two cubes that have exactly one variable that is 0 in one and 1 in the other:
+    /// the variable becomes `-` and the others are intersected
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a & b and !a & c -> b & c
+    /// let a: Cube = "11-".parse().unwrap();
+    /// let b: Cube = "0-1".parse().unwrap();
+    /// assert_eq!(a.consensus(&b), Some("-11".parse().unwrap()));
+    /// ```
+    pub fn consensus(&self, other: &Cube) -> Option<Cube> {
+        if self.distance(other) != 1 {
+            return None;
+        }
+        Some(Cube::new(
+            self.pairs(other)
+                .map(|(a, b)| match (a, b) {
+                    _ if opposite(a, b) => Literal::DontCare,
+                    (Literal::DontCare, b) => b,
+                    (a, _) => a,
+                })
+                .collect(),
+        ))
+    }
+
+    /// the rows of `self` that are not in `other` as disjoint cubes
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let a = Cube::universe(2);
+    /// let b: Cube = "11".parse().unwrap();
+    /// assert_eq!(a.sharp(&b), vec!["0-".parse().unwrap(), "10".parse().unwrap()]);
+    /// ```
+    pub fn sharp(&self, other: &Cube) -> Vec<Cube> {
+        if self.distance(other) > 0 {
+            return vec![self.clone()];
+        }
+        // the rows outside of `other` in one variable and inside in the ones before
+        let mut result = Vec::new();
+        let mut inside = self.clone();
+        for (i, (a, b)) in self.pairs(other).enumerate() {
+            if a == Literal::DontCare && b != Literal::DontCare {
+                let mut cube = inside.clone();
+                cube.literals[i] = negate(b);
+                result.push(cube);
+                inside.literals[i] = b;
+            }
+        }
+        result
+    }
+
+    /// the cube with the variable fixed to `value`, `None` if the cube is 0 there
+    fn restrict(&self, var: usize, value: bool) -> Option<Cube> {
+        match (self.literals[var], value) {
+            (Literal::One, false) | (Literal::Zero, true) => None,
+            _ => {
+                let mut cube = self.clone();
+                cube.literals[var] = Literal::DontCare;
+                Some(cube)
+            }
+        }
+    }
+
+    fn pairs<'a>(&'a self, other: &'a Cube) -> impl Iterator<Item = (Literal, Literal)> + 'a {
+        assert_eq!(
+            self.vars(),
+            other.vars(),
+            "the cubes have a different number of variables"
+        );
+        self.literals
+            .iter()
+            .copied()
+            .zip(other.literals.iter().copied())
+    }
+}
+
+impl std::fmt::Display for Cube {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for literal in &self.literals {
+            match literal {
+                Literal::Zero => write!(f, "0")?,
+                Literal::One => write!(f, "1")?,
+                Literal::DontCare => write!(f, "-")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Cube {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| match c {
+                '0' => Ok(Literal::Zero),
+                '1' => Ok(Literal::One),
+                '-' => Ok(Literal::DontCare),
+                c => Err(format!("expected 0, 1 or - got {}", c)),
+            })
+            .collect::<Result<Vec<Literal>, String>>()
+            .map(Cube::new)
+    }
+}
+
+/// a sum of products as a list of cubes over the same variables
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Cover {
+    vars: usize,
+    cubes: Vec<Cube>,
+}
+
+impl Cover {
+    /// the empty cover (the function 0)
+    pub fn new(vars: usize) -> Self {
+        Self {
+            vars,
+            cubes: Vec::new(),
+        }
+    }
+
+    pub fn from_cubes(vars: usize, cubes: Vec<Cube>) -> Result<Self, String> {
+        let mut cover = Self::new(vars);
+        for cube in cubes {
+            cover.push(cube)?;
+        }
+        Ok(cover)
+    }
+
+    /// a cube for every true row of a compressed table
+    pub fn from_table(table: &[bool]) -> Result<Self, String> {
+        if !table.len().is_power_of_two() {
+            return Err("table length is not a power of 2".to_string());
+        }
+        let vars = table.len().trailing_zeros() as usize;
+        Ok(Self {
+            vars,
+            cubes: (0..table.len())
+                .filter(|&row| table[row])
+                .map(|row| Cube::minterm(row, vars))
+                .collect(),
+        })
+    }
+
+    /// reads a sum of products (like the result of `dnf`), `names` are the variables of the cubes
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a & !b | c
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::And,
+    ///     Token::Not,
+    ///     Token::Var("b".to_string()),
+    ///     Token::Or,
+    ///     Token::Var("c".to_string()),
+    /// ];
+    /// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    ///
+    /// let cover = Cover::from_func(&func, &names).unwrap();
+    /// assert_eq!(cover.cubes(), &["10-".parse().unwrap(), "--1".parse().unwrap()]);
+    /// assert_eq!(cover.to_table(), Ok(parse(&func).unwrap()));
+    /// ```
+    pub fn from_func(func: &[Token], names: &[String]) -> Result<Self, String> {
+        validate_func(func)?;
+        let expr = Expr::build(func, names)?;
+        let mut cover = Self::new(names.len());
+        collect_cubes(&expr, &mut cover)?;
+        Ok(cover)
+    }
+
+    /// adds a cube, it must have the number of variables of the cover
+    pub fn push(&mut self, cube: Cube) -> Result<(), String> {
+        if cube.vars() != self.vars {
+            return Err(format!(
+                "expected a cube with {} variables got {}",
+                self.vars,
+                cube.vars()
+            ));
+        }
+        self.cubes.push(cube);
+        Ok(())
+    }
+
+    pub fn cubes(&self) -> &[Cube] {
+        &self.cubes
+    }
+
+    /// the number of variables
+    pub fn vars(&self) -> usize {
+        self.vars
+    }
+
+    /// writes the cover like `dnf` with parentheses, `0` if it has no cubes
+    pub fn to_func(&self, names: &[String]) -> Result<Vec<Token>, String> {
+        if names.len() != self.vars {
+            return Err(format!("expected {} names got {}", self.vars, names.len()));
+        }
+        if self.cubes.is_empty() {
+            return Ok(vec![Token::Zero]);
+        }
+        let mut func = Vec::new();
+        for cube in &self.cubes {
+            if !func.is_empty() {
+                func.push(Token::Or);
+            }
+            if cube.literal_count() == 0 {
+                func.push(Token::One);
+                continue;
+            }
+            func.push(Token::Open);
+            for (literal, name) in cube.literals.iter().zip(names) {
+                if *literal == Literal::DontCare {
+                    continue;
+                }
+                if func.last() != Some(&Token::Open) {
+                    func.push(Token::And);
+                }
+                if *literal == Literal::Zero {
+                    func.push(Token::Not);
+                }
+                func.push(Token::Var(name.clone()));
+            }
+            func.push(Token::Close);
+        }
+        Ok(func)
+    }
+
+    /// the compressed table of the cover
+    pub fn to_table(&self) -> Result<Vec<bool>, String> {
+        if self.vars >= usize::BITS as usize {
+            return Err(format!("too many variables {}", self.vars));
+        }
+        Ok((0..(1_usize << self.vars))
+            .map(|row| self.cubes.iter().any(|cube| cube.covers(row)))
+            .collect())
+    }
+
+    /// the rows that are in both covers
+    pub fn intersect(&self, other: &Cover) -> Result<Cover, String> {
+        self.check(other)?;
+        let cubes = self
+            .cubes
+            .iter()
+            .flat_map(|a| other.cubes.iter().filter_map(move |b| a.intersect(b)))
+            .collect();
+        Ok(self.with_cubes(cubes))
+    }
+
+    /// every row of the cube is covered by the cover
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// let cover = Cover::from_cubes(2, vec!["1-".parse().unwrap(), "-1".parse().unwrap()]).unwrap();
+    /// // neither cube contains 11 -> a & b but together they do
+    /// assert_eq!(cover.contains_cube(&"11".parse().unwrap()), Ok(true));
+    /// assert_eq!(cover.contains_cube(&"0-".parse().unwrap()), Ok(false));
+    /// ```
+    pub fn contains_cube(&self, cube: &Cube) -> Result<bool, String> {
+        Ok(self.cofactor(cube)?.is_tautology())
+    }
+
+    /// every row of `other` is covered by `self`
+    pub fn contains(&self, other: &Cover) -> Result<bool, String> {
+        self.check(other)?;
+        for cube in &other.cubes {
+            if !self.contains_cube(cube)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// the cover restricted to the rows of the cube,
+    /// the variables the cube fixes become `-`
+    pub fn cofactor(&self, cube: &Cube) -> Result<Cover, String> {
+        if cube.vars() != self.vars {
+            return Err(format!(
+                "expected a cube with {} variables got {}",
+                self.vars,
+                cube.vars()
+            ));
+        }
+        let cubes = self
+            .cubes
+            .iter()
+            .filter(|c| c.distance(cube) == 0)
+            .map(|c| {
+                Cube::new(
+                    c.pairs(cube)
+                        .map(|(a, b)| {
+                            if b == Literal::DontCare {
+                                a
+                            } else {
+                                Literal::DontCare
+                            }
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+        Ok(self.with_cubes(cubes))
+    }
+
+    /// the cover covers every row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a & b | !a | !b
+    /// let cubes = vec!["11".parse().unwrap(), "0-".parse().unwrap(), "-0".parse().unwrap()];
+    /// assert!(Cover::from_cubes(2, cubes).unwrap().is_tautology());
+    /// ```
+    pub fn is_tautology(&self) -> bool {
+        if self.cubes.iter().any(|cube| cube.literal_count() == 0) {
+            return true;
+        }
+        // a cover where every variable is only 1 or only 0
+        // is only a tautology if it has the universal cube
+        match self.split_var(true) {
+            Some(var) => {
+                self.restrict(var, true).is_tautology() && self.restrict(var, false).is_tautology()
+            }
+            None => false,
+        }
+    }
+
+    /// the rows the cover doesn't cover
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // !(a & b) -> !a | a & !b
+    /// let cover = Cover::from_cubes(2, vec!["11".parse().unwrap()]).unwrap();
+    /// let complement = cover.complement();
+    /// assert_eq!(complement.to_table(), Ok(vec![true, true, true, false]));
+    /// ```
+    pub fn complement(&self) -> Cover {
+        if self.cubes.is_empty() {
+            return self.with_cubes(vec![Cube::universe(self.vars)]);
+        }
+        if self.cubes.iter().any(|cube| cube.literal_count() == 0) {
+            return self.with_cubes(Vec::new());
+        }
+
+        let var = self.split_var(false).unwrap();
+        let ones = self.restrict(var, true).complement().cubes;
+        let zeros = self.restrict(var, false).complement().cubes;
+
+        // a cube that is in both halves doesn't need the variable
+        let mut cubes = Vec::new();
+        for cube in &ones {
+            if zeros.contains(cube) {
+                cubes.push(cube.clone());
+            } else {
+                let mut cube = cube.clone();
+                cube.literals[var] = Literal::One;
+                cubes.push(cube);
+            }
+        }
+        for cube in zeros {
+            if !ones.contains(&cube) {
+                let mut cube = cube;
+                cube.literals[var] = Literal::Zero;
+                cubes.push(cube);
+            }
+        }
+        self.with_cubes(cubes)
+    }
+
+    /// the rows of `self` that are not in `other`
+    pub fn sharp(&self, other: &Cover) -> Result<Cover, String> {
+        self.check(other)?;
+        let mut cubes = self.cubes.clone();
+        for b in &other.cubes {
+            cubes = cubes.iter().flat_map(|a| a.sharp(b)).collect();
+        }
+        Ok(self.with_cubes(cubes))
+    }
+
+    /// removes the cubes that are contained in another cube (single cube containment)
+    pub fn remove_contained(&mut self) {
+        let mut cubes: Vec<Cube> = Vec::new();
+        for cube in &self.cubes {
+            if cubes.iter().any(|c| c.contains(cube)) {
+                continue;
+            }
+            cubes.retain(|c| !cube.contains(c));
+            cubes.push(cube.clone());
+        }
+        self.cubes = cubes;
+    }
+
+    /// the cover with the variable fixed to `value`
+    fn restrict(&self, var: usize, value: bool) -> Cover {
+        let cubes = self
+            .cubes
+            .iter()
+            .filter_map(|cube| cube.restrict(var, value))
+            .collect();
+        self.with_cubes(cubes)
+    }
+
+    /// the variable that is not `-` in the most cubes,
+    /// only variables that are 0 in one cube and 1 in another if `binate`
+    fn split_var(&self, binate: bool) -> Option<usize> {
+        (0..self.vars)
+            .filter(|&var| {
+                let has = |literal| self.cubes.iter().any(|cube| cube.literals[var] == literal);
+                if binate {
+                    has(Literal::One) && has(Literal::Zero)
+                } else {
+                    has(Literal::One) || has(Literal::Zero)
+                }
+            })
+            .max_by_key(|&var| {
+                let count = self
+                    .cubes
+                    .iter()
+                    .filter(|cube| cube.literals[var] != Literal::DontCare)
+                    .count();
+                // the first variable wins a tie
+                (count, std::cmp::Reverse(var))
+            })
+    }
+
+    fn with_cubes(&self, cubes: Vec<Cube>) -> Cover {
+        Cover {
+            vars: self.vars,
+            cubes,
+        }
+    }
+
+    fn check(&self, other: &Cover) -> Result<(), String> {
+        if self.vars != other.vars {
+            return Err(format!(
+                "expected a cover with {} variables got {}",
+                self.vars, other.vars
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn opposite(a: Literal, b: Literal) -> bool {
+    matches!(
+        (a, b),
+        (Literal::One, Literal::Zero) | (Literal::Zero, Literal::One)
+    )
+}
+
+fn negate(literal: Literal) -> Literal {
+    match literal {
+        Literal::One => Literal::Zero,
+        Literal::Zero => Literal::One,
+        Literal::DontCare => Literal::DontCare,
+    }
+}
+
+fn collect_cubes(expr: &Expr, cover: &mut Cover) -> Result<(), String> {
+    match expr {
+        Expr::Binary(Token::Or, left, right) => {
+            collect_cubes(left, cover)?;
+            collect_cubes(right, cover)
+        }
+        Expr::Const(false) => Ok(()),
+        term => {
+            // a term with `0` or `x & !x` has no rows
+            if let Some(cube) = product_literals(term)?.and_then(|literals| {
+                let mut cube = Cube::universe(cover.vars);
+                for (index, literal) in literals {
+                    if opposite(cube.literals[index], literal) {
+                        return None;
+                    }
+                    cube.literals[index] = literal;
+                }
+                Some(cube)
+            }) {
+                cover.cubes.push(cube);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// the literals of a product term like `x & !y & 1` (the index of the variable and its value)
+/// in the order they appear, `None` if the term has a `0`
+pub(crate) fn product_literals(expr: &Expr) -> Result<Option<Vec<(usize, Literal)>>, String> {
+    let mut literals = Vec::new();
+    Ok(if collect_literals(expr, &mut literals)? {
+        Some(literals)
+    } else {
+        None
+    })
+}
+
+/// returns `false` if the term has a `0`
+fn collect_literals(expr: &Expr, literals: &mut Vec<(usize, Literal)>) -> Result<bool, String> {
+    match expr {
+        Expr::Binary(Token::And, left, right) => {
+            // the right side is read even after a `0` so every error is found
+            let left = collect_literals(left, literals)?;
+            let right = collect_literals(right, literals)?;
+            Ok(left && right)
+        }
+        Expr::Const(value) => Ok(*value),
+        Expr::Var(index) => {
+            literals.push((*index, Literal::One));
+            Ok(true)
+        }
+        Expr::Not(inner) => match &**inner {
+            Expr::Var(index) => {
+                literals.push((*index, Literal::Zero));
+                Ok(true)
+            }
+            _ => Err("the function is not a sum of products".to_string()),
+        },
+        _ => Err("the function is not a sum of products".to_string()),
+    }
+}
@@ -19,11 +19,7 @@ use crate::{update_values, validate_func, validate_tabel, Token};
 /// ];
 /// assert_eq!(bool_algebra::dnf(&table, &names, true), Ok(dnf));
 /// ```
-pub fn dnf(
-    table: &Vec<bool>,
-    names: &Vec<String>,
-    with_parentheses: bool,
-) -> Result<Vec<Token>, String> {
+pub fn dnf(table: &[bool], names: &[String], with_parentheses: bool) -> Result<Vec<Token>, String> {
     validate_tabel(table, names)?;
     let names: Vec<Token> = names.iter().map(|name| Token::Var(name.clone())).collect();
 
@@ -33,7 +29,7 @@ pub fn dnf(
     let mut index = 0;
     loop {
         if table[index] {
-            if dnf.len() != 0 {
+            if !dnf.is_empty() {
                 dnf.push(Token::Or);
             }
             if with_parentheses {
@@ -0,0 +1,348 @@
+use crate::cube::{opposite, Cover, Cube, Literal};
+use crate::Token;
+use std::collections::HashMap;
+
+/// a cube of a multi output cover: the product term and the outputs it is used for (bit `j` is output `j`)
+#[derive(PartialEq, Debug, Clone, Eq)]
+struct MultiCube {
+    cube: Cube,
+    outputs: u64,
+}
+
+/// the on-set and off-set of every output as lists of cubes
+struct Sets {
+    len: usize,
+    on: Vec<Vec<Cube>>,
+    off: Vec<Vec<Cube>>,
+}
+
+/// minimizes the functions of a table with the heuristic of espresso,
+/// returns a sum of products for every output (like `dnf` with parentheses)
+///
+/// the arguments are the same as for `parse_fill`: rows of `in_len` inputs followed by `out_len` outputs,
+/// the rows that are missing get the value `fill` or are don't-cares if `fill` is `None`,
+/// `names` are the names of the inputs
+///
+/// the functions are never written as full tables: the cover starts with the true rows
+/// and is improved by a loop of
+/// - EXPAND: removes the literals of every term that are not needed to keep it from covering a false row
+///   (it keeps the literals that keep it apart from the most false rows first),
+///   adds the outputs the term doesn't make false and drops the terms it covers
+/// - IRREDUNDANT: removes the terms (and outputs of terms) that are covered by the other terms
+/// - REDUCE: shrinks every term to the smallest term that still covers the true rows only it covers
+///
+/// until the number of terms and literals stops getting smaller,
+/// terms are shared between the outputs, the result is not always the smallest possible
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a b | x y
+/// let table = vec![
+///     false, false, false, false,
+///     false, true, true, false,
+///     true, true, true, true,
+///     // 1 0 is missing (a don't-care)
+/// ];
+/// let names = vec!["a".to_string(), "b".to_string()];
+///
+/// let funcs = espresso(2, 2, table, None, &names).unwrap();
+/// // x = b, y = a
+/// assert_eq!(funcs[0], vec![Token::Open, Token::Var("b".to_string()), Token::Close]);
+/// assert_eq!(funcs[1], vec![Token::Open, Token::Var("a".to_string()), Token::Close]);
+/// ```
+pub fn espresso(
+    in_len: usize,
+    out_len: usize,
+    table: Vec<bool>,
+    fill: Option<bool>,
+    names: &[String],
+) -> Result<Vec<Vec<Token>>, String> {
+    if names.len() != in_len {
+        return Err(format!("expected {} names got {}", in_len, names.len()));
+    }
+    if in_len >= usize::BITS as usize {
+        return Err(format!("too many inputs {}", in_len));
+    }
+    if out_len > u64::BITS as usize {
+        return Err(format!("too many outputs {}", out_len));
+    }
+    if in_len + out_len == 0 || !table.len().is_multiple_of(in_len + out_len) {
+        return Err("incorrect table shape".to_string());
+    }
+
+    let sets = Sets::new(in_len, out_len, &table, fill)?;
+    let mut cover = initial_cover(&sets);
+    cover = sets.expand(cover);
+    cover = sets.irredundant(cover)?;
+    loop {
+        let mut next = sets.reduce(cover.clone())?;
+        next = sets.expand(next);
+        next = sets.irredundant(next)?;
+        if cost(&next) >= cost(&cover) {
+            break;
+        }
+        cover = next;
+    }
+
+    (0..out_len)
+        .map(|output| {
+            let mut cubes: Vec<Cube> = cover
+                .iter()
+                .filter(|cube| cube.outputs >> output & 1 == 1)
+                .map(|cube| cube.cube.clone())
+                .collect();
+            cubes.sort_by_cached_key(|cube| cube.to_string());
+            Cover::from_cubes(in_len, cubes)?.to_func(names)
+        })
+        .collect()
+}
+
+impl Sets {
+    fn new(
+        in_len: usize,
+        out_len: usize,
+        table: &[bool],
+        fill: Option<bool>,
+    ) -> Result<Sets, String> {
+        // a later row replaces an earlier one like in `parse_fill`
+        let mut rows: HashMap<usize, Vec<bool>> = HashMap::new();
+        let mut order = Vec::new();
+        for chunk in table.chunks(in_len + out_len) {
+            let row = chunk[..in_len]
+                .iter()
+                .fold(0, |row, &b| row << 1 | b as usize);
+            if rows.insert(row, chunk[in_len..].to_vec()).is_none() {
+                order.push(row);
+            }
+        }
+
+        let mut on = vec![Vec::new(); out_len];
+        let mut off = vec![Vec::new(); out_len];
+        for row in &order {
+            for (output, &value) in rows[row].iter().enumerate() {
+                let set = if value { &mut on } else { &mut off };
+                set[output].push(Cube::minterm(*row, in_len));
+            }
+        }
+
+        if let Some(value) = fill {
+            let given = order
+                .iter()
+                .map(|&row| Cube::minterm(row, in_len))
+                .collect();
+            let missing = Cover::from_cubes(in_len, given)?.complement();
+            let set = if value { &mut on } else { &mut off };
+            for cubes in set.iter_mut() {
+                cubes.extend(missing.cubes().iter().cloned());
+            }
+        }
+
+        Ok(Sets {
+            len: in_len,
+            on,
+            off,
+        })
+    }
+
+    /// the cube doesn't cover a false row of the outputs
+    fn valid(&self, cube: &Cube, outputs: u64) -> bool {
+        self.off.iter().enumerate().all(|(output, off)| {
+            outputs >> output & 1 == 0 || off.iter().all(|o| cube.distance(o) > 0)
+        })
+    }
+
+    fn expand(&self, cover: Vec<MultiCube>) -> Vec<MultiCube> {
+        let mut cover = cover;
+        // the big cubes first, they are the most likely to cover others
+        cover.sort_by_key(|cube| cube.cube.literal_count());
+
+        let mut result: Vec<MultiCube> = Vec::new();
+        for (i, cube) in cover.iter().enumerate() {
+            if result.iter().any(|done| contains_cube(done, cube)) {
+                continue;
+            }
+            let mut cube = cube.clone();
+            let rest = &cover[i + 1..];
+
+            // grow toward the closest other cubes while the cube stays valid
+            let mut others: Vec<&MultiCube> = rest
+                .iter()
+                .filter(|other| other.outputs & cube.outputs == cube.outputs)
+                .collect();
+            others.sort_by_key(|other| {
+                std::cmp::Reverse(cube.cube.supercube(&other.cube).literal_count())
+            });
+            for other in others {
+                let supercube = cube.cube.supercube(&other.cube);
+                if supercube != cube.cube && self.valid(&supercube, cube.outputs) {
+                    cube.cube = supercube;
+                }
+            }
+
+            // the variables that keep the cube apart from every false row,
+            // the cube keeps a smallest set of literals that has one of them for every row
+            let literals = cube.cube.literals();
+            let blocking: Vec<Vec<usize>> = self
+                .off
+                .iter()
+                .enumerate()
+                .filter(|(output, _)| cube.outputs >> output & 1 == 1)
+                .flat_map(|(_, off)| off.iter())
+                .map(|o| {
+                    (0..self.len)
+                        .filter(|&var| opposite(literals[var], o.literals()[var]))
+                        .collect()
+                })
+                .collect();
+            let mut keep = vec![false; self.len];
+            loop {
+                let open: Vec<&Vec<usize>> = blocking
+                    .iter()
+                    .filter(|vars| vars.iter().all(|&var| !keep[var]))
+                    .collect();
+                if open.is_empty() {
+                    break;
+                }
+                // the literal that blocks the most rows,
+                // on a tie the one the other cubes agree with (so the cube can cover them)
+                // and then the first one
+                let best = (0..self.len)
+                    .rev()
+                    .filter(|&var| literals[var] != Literal::DontCare && !keep[var])
+                    .max_by_key(|&var| {
+                        let blocks = open.iter().filter(|vars| vars.contains(&var)).count();
+                        let agree = rest
+                            .iter()
+                            .filter(|other| other.cube.literals()[var] == literals[var])
+                            .count();
+                        (blocks, agree)
+                    });
+                match best {
+                    Some(var) => keep[var] = true,
+                    None => break,
+                }
+            }
+            cube.cube = Cube::new(
+                literals
+                    .iter()
+                    .zip(&keep)
+                    .map(|(&literal, &keep)| if keep { literal } else { Literal::DontCare })
+                    .collect(),
+            );
+
+            for output in 0..self.on.len() {
+                if self.valid(&cube.cube, 1 << output) {
+                    cube.outputs |= 1 << output;
+                }
+            }
+            result.retain(|done| !contains_cube(&cube, done));
+            result.push(cube);
+        }
+        result
+    }
+
+    fn irredundant(&self, cover: Vec<MultiCube>) -> Result<Vec<MultiCube>, String> {
+        let mut cover = cover;
+        // try to remove the small cubes first
+        cover.sort_by_key(|cube| std::cmp::Reverse(cube.cube.literal_count()));
+
+        for i in 0..cover.len() {
+            for output in 0..self.on.len() {
+                if cover[i].outputs >> output & 1 == 0 {
+                    continue;
+                }
+                let others = self.others(&cover, i, output)?;
+                let mut needed = false;
+                for on in &self.on[output] {
+                    if let Some(part) = cover[i].cube.intersect(on) {
+                        if !others.contains_cube(&part)? {
+                            needed = true;
+                            break;
+                        }
+                    }
+                }
+                if !needed {
+                    cover[i].outputs &= !(1 << output);
+                }
+            }
+        }
+        cover.retain(|cube| cube.outputs != 0);
+        Ok(cover)
+    }
+
+    fn reduce(&self, cover: Vec<MultiCube>) -> Result<Vec<MultiCube>, String> {
+        let mut cover = cover;
+        cover.sort_by_key(|cube| cube.cube.literal_count());
+
+        for i in 0..cover.len() {
+            let mut reduced: Option<Cube> = None;
+            let mut outputs = 0;
+            for output in 0..self.on.len() {
+                if cover[i].outputs >> output & 1 == 0 {
+                    continue;
+                }
+                let others = self.others(&cover, i, output)?;
+                for on in &self.on[output] {
+                    let part = match cover[i].cube.intersect(on) {
+                        Some(part) => part,
+                        None => continue,
+                    };
+                    if !others.contains_cube(&part)? {
+                        reduced = Some(match reduced {
+                            Some(cube) => cube.supercube(&part),
+                            None => part,
+                        });
+                        outputs |= 1 << output;
+                    }
+                }
+            }
+            match reduced {
+                Some(cube) => cover[i] = MultiCube { cube, outputs },
+                None => cover[i].outputs = 0,
+            }
+        }
+        cover.retain(|cube| cube.outputs != 0);
+        Ok(cover)
+    }
+
+    /// the cubes of all cubes except `i` that are used for the output
+    fn others(&self, cover: &[MultiCube], i: usize, output: usize) -> Result<Cover, String> {
+        let cubes = cover
+            .iter()
+            .enumerate()
+            .filter(|&(j, cube)| j != i && cube.outputs >> output & 1 == 1)
+            .map(|(_, cube)| cube.cube.clone())
+            .collect();
+        Cover::from_cubes(self.len, cubes)
+    }
+}
+
+/// every true row is a cube, rows that are true for more outputs are shared
+fn initial_cover(sets: &Sets) -> Vec<MultiCube> {
+    let mut cover: Vec<MultiCube> = Vec::new();
+    for (output, cubes) in sets.on.iter().enumerate() {
+        for on in cubes {
+            match cover.iter_mut().find(|cube| cube.cube == *on) {
+                Some(cube) => cube.outputs |= 1 << output,
+                None => cover.push(MultiCube {
+                    cube: on.clone(),
+                    outputs: 1 << output,
+                }),
+            }
+        }
+    }
+    cover
+}
+
+/// the number of cubes and literals
+fn cost(cover: &[MultiCube]) -> (usize, usize) {
+    let literals = cover.iter().map(|cube| cube.cube.literal_count()).sum();
+    (cover.len(), literals)
+}
+
+fn contains_cube(cube: &MultiCube, other: &MultiCube) -> bool {
+    cube.cube.contains(&other.cube) && other.outputs & !cube.outputs == 0
+}
@@ -0,0 +1,94 @@
+use crate::{get_names, validate_func, CompiledExpr, Token};
+use std::collections::HashMap;
+
+/// evaluates the function for a single assignment instead of building the whole table
+///
+/// returns `Err` if the function is invalid or a variable has no value in `values`,
+/// values of variables that are not in the function are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+/// use std::collections::HashMap;
+///
+/// // a & !b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Not,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let mut values = HashMap::new();
+/// values.insert("a".to_string(), true);
+/// assert!(eval(&func, &values).is_err());
+///
+/// values.insert("b".to_string(), false);
+/// assert_eq!(eval(&func, &values), Ok(true));
+/// ```
+pub fn eval(func: &[Token], values: &HashMap<String, bool>) -> Result<bool, String> {
+    validate_func(func)?;
+
+    let names = get_names(func);
+    let mut assignment = Vec::with_capacity(names.len());
+    for name in &names {
+        match values.get(name) {
+            Some(&value) => assignment.push(value),
+            None => return Err(format!("variable {} is not bound", name)),
+        }
+    }
+
+    Ok(CompiledExpr::with_order(func, &names)?.eval(&assignment))
+}
+
+/// a function that is parsed once and can then be evaluated for many assignments
+///
+/// the values are passed as a slice in the order of `get_names`
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // b → a
+/// let func = vec![
+///     Token::Var("b".to_string()),
+///     Token::ImplicAB,
+///     Token::Var("a".to_string()),
+/// ];
+///
+/// let evaluator = Evaluator::new(&func).unwrap();
+/// assert_eq!(evaluator.names(), &["a".to_string(), "b".to_string()]);
+/// assert_eq!(evaluator.eval(&[false, true]), Ok(false));
+/// assert_eq!(evaluator.eval(&[true, true]), Ok(true));
+/// assert!(evaluator.eval(&[true]).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Evaluator {
+    compiled: CompiledExpr,
+}
+
+impl Evaluator {
+    pub fn new(func: &[Token]) -> Result<Self, String> {
+        Ok(Self {
+            compiled: CompiledExpr::new(func)?,
+        })
+    }
+
+    /// the names of the variables in the order the values are expected
+    pub fn names(&self) -> &[String] {
+        self.compiled.names()
+    }
+
+    pub fn eval(&self, values: &[bool]) -> Result<bool, String> {
+        if values.len() != self.names().len() {
+            return Err(format!(
+                "unexpected number of values, expected {} got {}",
+                self.names().len(),
+                values.len()
+            ));
+        }
+        Ok(self.compiled.eval(values))
+    }
+}
@@ -0,0 +1,250 @@
+use crate::circuit::{row_bits, value};
+use crate::Circuit;
+
+/// the wire of a stuck-at fault
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum FaultSite {
+    /// the output of a gate (and every wire it drives)
+    Output(usize),
+    /// an input of a gate: the index of the gate and the index of the input
+    Input(usize, usize),
+}
+
+/// a single stuck-at fault: the wire always has the value `stuck_at`
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub struct Fault {
+    pub site: FaultSite,
+    pub stuck_at: bool,
+}
+
+/// the result of `Circuit::test_set`
+#[derive(PartialEq, Debug, Clone)]
+pub struct TestSet {
+    /// the rows (assignments of the inputs in the order of the table) to apply
+    pub rows: Vec<usize>,
+    /// the faults that no row can detect (they don't change any output)
+    pub undetectable: Vec<Fault>,
+    /// the percentage of all faults the rows detect
+    pub coverage: f64,
+}
+
+impl Circuit {
+    /// all single stuck-at-0 and stuck-at-1 faults on the outputs and inputs of every gate
+    pub fn faults(&self) -> Vec<Fault> {
+        let mut faults = Vec::new();
+        for (index, gate) in self.gates().iter().enumerate() {
+            let sites = std::iter::once(FaultSite::Output(index))
+                .chain((0..gate.inputs.len()).map(|pin| FaultSite::Input(index, pin)));
+            for site in sites {
+                for &stuck_at in [false, true].iter() {
+                    faults.push(Fault { site, stuck_at });
+                }
+            }
+        }
+        faults
+    }
+
+    /// computes the outputs for 64 assignments at once with a fault, see `simulate_bits`
+    pub fn simulate_fault(&self, fault: &Fault, inputs: &[u64]) -> Result<Vec<u64>, String> {
+        if inputs.len() != self.inputs().len() {
+            return Err(format!(
+                "expected {} values got {}",
+                self.inputs().len(),
+                inputs.len()
+            ));
+        }
+        let gate = match fault.site {
+            FaultSite::Output(gate) | FaultSite::Input(gate, _) => gate,
+        };
+        if gate >= self.gates().len() {
+            return Err(format!("gate {} doesn't exist", gate));
+        }
+
+        let stuck = if fault.stuck_at { !0 } else { 0 };
+        let mut values: Vec<u64> = Vec::with_capacity(self.gates().len());
+        for (index, gate) in self.gates().iter().enumerate() {
+            let mut gate_inputs: Vec<u64> = gate
+                .inputs
+                .iter()
+                .map(|signal| value(*signal, inputs, &values))
+                .collect();
+            match fault.site {
+                FaultSite::Input(faulty, pin) if faulty == index => {
+                    match gate_inputs.get_mut(pin) {
+                        Some(input) => *input = stuck,
+                        None => return Err(format!("gate {} has no input {}", index, pin)),
+                    }
+                }
+                _ => (),
+            }
+            values.push(match fault.site {
+                FaultSite::Output(faulty) if faulty == index => stuck,
+                _ => gate.kind.eval_bits(&gate_inputs),
+            });
+        }
+        Ok(self
+            .outputs()
+            .iter()
+            .map(|(_, signal)| value(*signal, inputs, &values))
+            .collect())
+    }
+
+    /// the rows (in the order of the table) where the fault changes at least one output
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a & b
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::And,
+    ///     Token::Var("b".to_string()),
+    /// ];
+    /// let circuit = Circuit::from_funcs(&[func]).unwrap();
+    ///
+    /// // the output stuck at 0 is only visible if both inputs are 1
+    /// let fault = Fault { site: FaultSite::Output(0), stuck_at: false };
+    /// assert_eq!(circuit.detecting_rows(&fault), Ok(vec![0b11]));
+    ///
+    /// // the first input stuck at 1 is visible if a is 0 and b is 1
+    /// let fault = Fault { site: FaultSite::Input(0, 0), stuck_at: true };
+    /// assert_eq!(circuit.detecting_rows(&fault), Ok(vec![0b01]));
+    /// ```
+    pub fn detecting_rows(&self, fault: &Fault) -> Result<Vec<usize>, String> {
+        let detected = self.detection(fault)?;
+        Ok((0..detected.len() * 64)
+            .filter(|&row| detected[row / 64] >> (row % 64) & 1 == 1)
+            .collect())
+    }
+
+    /// the rows that detect the fault as a bit set
+    fn detection(&self, fault: &Fault) -> Result<Vec<u64>, String> {
+        let len = self.inputs().len();
+        if len >= 64 {
+            return Err(format!("too many inputs {}", len));
+        }
+
+        let rows = 1_usize << len;
+        let mut detected = Vec::with_capacity(rows.div_ceil(64));
+        for start in (0..rows).step_by(64) {
+            let count = usize::min(64, rows - start);
+            let inputs = row_bits(start, count, len);
+            let good = self.simulate_bits(&inputs)?;
+            let bad = self.simulate_fault(fault, &inputs)?;
+            let mask = if count == 64 { !0 } else { (1 << count) - 1 };
+            let bits = good.iter().zip(bad).fold(0, |bits, (g, b)| bits | (g ^ b));
+            detected.push(bits & mask);
+        }
+        Ok(detected)
+    }
+
+    /// the percentage of all faults (see `faults`) that are detected by at least one of the rows
+    pub fn fault_coverage(&self, rows: &[usize]) -> Result<f64, String> {
+        if let Some(row) = rows.iter().find(|&&row| row >> self.inputs().len() != 0) {
+            return Err(format!("row {} doesn't exist", row));
+        }
+        let faults = self.faults();
+        if faults.is_empty() {
+            return Ok(100.0);
+        }
+        let mut detected = 0;
+        for fault in &faults {
+            let bits = self.detection(fault)?;
+            if rows
+                .iter()
+                .any(|&row| bits[row / 64] >> (row % 64) & 1 == 1)
+            {
+                detected += 1;
+            }
+        }
+        Ok(detected as f64 * 100.0 / faults.len() as f64)
+    }
+
+    /// generates a test set (automatic test pattern generation) for all single stuck-at faults
+    /// by comparing the table of every faulty circuit with the table of the circuit
+    ///
+    /// the rows are picked greedily (the row that detects the most faults that are not detected yet)
+    /// and then every row that is not needed is removed again,
+    /// so the test set is minimal (no row can be removed) but not always the smallest possible
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a & b
+    /// let func = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::And,
+    ///     Token::Var("b".to_string()),
+    /// ];
+    /// let circuit = Circuit::from_funcs(&[func]).unwrap();
+    ///
+    /// let test_set = circuit.test_set().unwrap();
+    /// let mut rows = test_set.rows.clone();
+    /// rows.sort();
+    /// assert_eq!(rows, vec![0b01, 0b10, 0b11]);
+    /// assert_eq!(test_set.coverage, 100.0);
+    /// ```
+    pub fn test_set(&self) -> Result<TestSet, String> {
+        let faults = self.faults();
+        let mut detections = Vec::new();
+        let mut undetectable = Vec::new();
+        for fault in &faults {
+            let bits = self.detection(fault)?;
+            if bits.iter().all(|&b| b == 0) {
+                undetectable.push(*fault);
+            } else {
+                detections.push(bits);
+            }
+        }
+
+        let detects = |bits: &[u64], row: usize| bits[row / 64] >> (row % 64) & 1 == 1;
+        let rows = 1_usize << self.inputs().len();
+        let mut remaining: Vec<&Vec<u64>> = detections.iter().collect();
+        let mut chosen = Vec::new();
+        while !remaining.is_empty() {
+            let best = (0..rows)
+                .max_by_key(|&row| {
+                    let count = remaining.iter().filter(|bits| detects(bits, row)).count();
+                    // the first row wins a tie
+                    (count, std::cmp::Reverse(row))
+                })
+                .unwrap();
+            chosen.push(best);
+            remaining.retain(|bits| !detects(bits, best));
+        }
+
+        // remove the rows whose faults are all detected by other rows
+        let mut i = 0;
+        while i < chosen.len() {
+            let others: Vec<usize> = chosen
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &row)| row)
+                .collect();
+            if detections
+                .iter()
+                .all(|bits| others.iter().any(|&row| detects(bits, row)))
+            {
+                chosen.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        let coverage = if faults.is_empty() {
+            100.0
+        } else {
+            detections.len() as f64 * 100.0 / faults.len() as f64
+        };
+        Ok(TestSet {
+            rows: chosen,
+            undetectable,
+            coverage,
+        })
+    }
+}
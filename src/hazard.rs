@@ -0,0 +1,320 @@
+use crate::cube::{opposite, product_literals, Cover, Cube, Literal};
+use crate::parser::Expr;
+use crate::{validate_func, validate_tabel, Token};
+
+/// the type of a static hazard
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+pub enum HazardKind {
+    /// the output should stay 1 but can glitch to 0
+    Static1,
+    /// the output should stay 0 but can glitch to 1
+    Static0,
+}
+
+/// a static hazard of a two level circuit when `var` changes
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    /// the rows of the table before and after the change (`var` is 0 in the first row)
+    pub rows: (usize, usize),
+    pub var: String,
+}
+
+/// a product term of the sum of products, the variables with `x & !x` are `-` in the cube
+/// and listed in `contradictions` (the term is 0 but it causes static-0 hazards)
+#[derive(PartialEq, Debug, Clone, Eq)]
+struct Product {
+    cube: Cube,
+    contradictions: Vec<usize>,
+}
+
+impl Product {
+    fn new(cube: Cube) -> Self {
+        Self {
+            cube,
+            contradictions: Vec::new(),
+        }
+    }
+
+    /// the product of the literals, a variable that appears as `x` and `!x` is a contradiction
+    fn from_literals(len: usize, literals: &[(usize, Literal)]) -> Self {
+        let mut cube = vec![Literal::DontCare; len];
+        let mut contradictions = Vec::new();
+        for &(index, literal) in literals {
+            if contradictions.contains(&index) {
+                continue;
+            }
+            if opposite(cube[index], literal) {
+                cube[index] = Literal::DontCare;
+                contradictions.push(index);
+            } else {
+                cube[index] = literal;
+            }
+        }
+        Self {
+            cube: Cube::new(cube),
+            contradictions,
+        }
+    }
+
+    fn covers(&self, row: usize) -> bool {
+        self.contradictions.is_empty() && self.cube.covers(row)
+    }
+}
+
+/// finds the static hazards of a sum of products (like the result of `dnf`) built with two levels of gates
+///
+/// a static-1 hazard are two adjacent true rows (they only differ in one variable)
+/// that are not covered by the same product term,
+/// a static-0 hazard are two adjacent false rows and a product term that contains the changing variable
+/// as `x` and `!x` while the rest of the term is true
+///
+/// `table` has to be the table of the function over `names`
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b | !a & c
+/// let sop = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Not,
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("c".to_string()),
+/// ];
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+/// let table = parse(&sop).unwrap();
+///
+/// // a changes while b and c are 1
+/// assert_eq!(
+///     static_hazards(&sop, &table, &names),
+///     Ok(vec![Hazard {
+///         kind: HazardKind::Static1,
+///         rows: (0b011, 0b111),
+///         var: "a".to_string(),
+///     }])
+/// );
+/// ```
+pub fn static_hazards(
+    sop: &[Token],
+    table: &[bool],
+    names: &[String],
+) -> Result<Vec<Hazard>, String> {
+    let products = sop_products(sop, table, names)?;
+    Ok(find_hazards(&products, table, names))
+}
+
+/// adds consensus terms to a sum of products until it has no static-1 hazards
+/// and removes the terms that contain `x` and `!x` (which cause static-0 hazards),
+/// the function stays the same
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b | !a & c
+/// let sop = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Not,
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("c".to_string()),
+/// ];
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+/// let table = parse(&sop).unwrap();
+///
+/// let cover = hazard_free_cover(&sop, &table, &names).unwrap();
+/// assert_eq!(static_hazards(&cover, &table, &names), Ok(vec![]));
+/// // the consensus term b & c is added
+/// assert_eq!(
+///     &cover[cover.len() - 5..],
+///     &[
+///         Token::Open,
+///         Token::Var("b".to_string()),
+///         Token::And,
+///         Token::Var("c".to_string()),
+///         Token::Close,
+///     ]
+/// );
+/// ```
+pub fn hazard_free_cover(
+    sop: &[Token],
+    table: &[bool],
+    names: &[String],
+) -> Result<Vec<Token>, String> {
+    let mut products: Vec<Product> = sop_products(sop, table, names)?
+        .into_iter()
+        .filter(|product| product.contradictions.is_empty())
+        .collect();
+
+    loop {
+        let hazards: Vec<Hazard> = find_hazards(&products, table, names)
+            .into_iter()
+            .filter(|hazard| hazard.kind == HazardKind::Static1)
+            .collect();
+        if hazards.is_empty() {
+            break;
+        }
+
+        // the consensus terms that are not contained in a term yet
+        let mut new_cubes: Vec<Cube> = Vec::new();
+        for (i, a) in products.iter().enumerate() {
+            for b in &products[i + 1..] {
+                if let Some(cube) = a.cube.consensus(&b.cube) {
+                    if !products.iter().any(|p| p.cube.contains(&cube))
+                        && !new_cubes.iter().any(|c| c.contains(&cube))
+                    {
+                        new_cubes.push(cube);
+                    }
+                }
+            }
+        }
+        if new_cubes.is_empty() {
+            return Err("the hazards can't be removed with consensus terms".to_string());
+        }
+
+        // prefer the terms that remove a hazard, the others are only needed for the next round
+        let covering: Vec<Cube> = new_cubes
+            .iter()
+            .filter(|cube| {
+                hazards
+                    .iter()
+                    .any(|hazard| cube.covers(hazard.rows.0) && cube.covers(hazard.rows.1))
+            })
+            .cloned()
+            .collect();
+        let added = if covering.is_empty() {
+            new_cubes
+        } else {
+            covering
+        };
+        products.extend(added.into_iter().map(Product::new));
+    }
+
+    let cubes = products.into_iter().map(|product| product.cube).collect();
+    Cover::from_cubes(names.len(), cubes)?.to_func(names)
+}
+
+/// reads the product terms of a sum of products and checks it against the table
+fn sop_products(sop: &[Token], table: &[bool], names: &[String]) -> Result<Vec<Product>, String> {
+    validate_func(sop)?;
+    validate_tabel(table, names)?;
+    let expr = Expr::build(sop, names)?;
+
+    let mut products = Vec::new();
+    collect_products(&expr, names.len(), &mut products)?;
+
+    for (row, &value) in table.iter().enumerate() {
+        if products.iter().any(|product| product.covers(row)) != value {
+            return Err("the table doesn't match the function".to_string());
+        }
+    }
+    Ok(products)
+}
+
+fn collect_products(expr: &Expr, len: usize, products: &mut Vec<Product>) -> Result<(), String> {
+    match expr {
+        Expr::Binary(Token::Or, left, right) => {
+            collect_products(left, len, products)?;
+            collect_products(right, len, products)
+        }
+        Expr::Const(false) => Ok(()),
+        term => {
+            // a term with a `0` has no rows and can't cause a hazard
+            if let Some(literals) = product_literals(term)? {
+                products.push(Product::from_literals(len, &literals));
+            }
+            Ok(())
+        }
+    }
+}
+
+fn find_hazards(products: &[Product], table: &[bool], names: &[String]) -> Vec<Hazard> {
+    let len = names.len();
+    let mut hazards = Vec::new();
+    for row in 0..table.len() {
+        for (i, name) in names.iter().enumerate() {
+            let bit = 1 << (len - 1 - i);
+            if row & bit != 0 || table[row] != table[row | bit] {
+                continue;
+            }
+            let other = row | bit;
+
+            let hazard = if table[row] {
+                !products
+                    .iter()
+                    .any(|product| product.covers(row) && product.covers(other))
+            } else {
+                // the term is x & !x & (rest), the rest is true in both rows
+                products
+                    .iter()
+                    .any(|product| product.contradictions == [i] && product.cube.covers(row))
+            };
+            if hazard {
+                hazards.push(Hazard {
+                    kind: if table[row] {
+                        HazardKind::Static1
+                    } else {
+                        HazardKind::Static0
+                    },
+                    rows: (row, other),
+                    var: name.clone(),
+                });
+            }
+        }
+    }
+    hazards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contradictions() {
+        // a & !a & b | !c & c & a & !a
+        let func = vec![
+            Token::Var("a".to_string()),
+            Token::And,
+            Token::Not,
+            Token::Var("a".to_string()),
+            Token::And,
+            Token::Var("b".to_string()),
+            Token::Or,
+            Token::Not,
+            Token::Var("c".to_string()),
+            Token::And,
+            Token::Var("c".to_string()),
+            Token::And,
+            Token::Var("a".to_string()),
+            Token::And,
+            Token::Not,
+            Token::Var("a".to_string()),
+        ];
+        let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let products = sop_products(&func, &[false; 8], &names).unwrap();
+        assert_eq!(
+            products,
+            vec![
+                Product {
+                    cube: Cube::new(vec![Literal::DontCare, Literal::One, Literal::DontCare]),
+                    contradictions: vec![0],
+                },
+                Product {
+                    cube: Cube::new(vec![Literal::DontCare; 3]),
+                    contradictions: vec![2, 0],
+                },
+            ]
+        );
+        assert!(!products[0].covers(0b110));
+    }
+}
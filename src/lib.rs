@@ -1,11 +1,59 @@
+mod cofactor;
+mod compile;
+mod aig;
+mod blocks;
+mod circuit;
+mod blif;
+mod cnf;
+mod completeness;
+mod cube;
 mod dnf;
+mod espresso;
+mod eval;
+mod fault;
+mod hazard;
+mod metrics;
+mod models;
+mod npn;
+mod order;
 mod parser;
+mod quantify;
+mod rows;
+mod sensitivity;
+mod spectrum;
+mod substitute;
+mod support;
+mod symmetry;
 mod token;
 mod utils;
 mod table_parser;
 
+pub use cofactor::*;
+pub use compile::CompiledExpr;
+pub use aig::*;
+pub use blocks::*;
+pub use blif::*;
+pub use circuit::*;
+pub use cnf::*;
+pub use completeness::*;
+pub use cube::*;
 pub use dnf::dnf;
-pub use parser::parse;
+pub use espresso::*;
+pub use eval::*;
+pub use fault::*;
+pub use hazard::*;
+pub use metrics::*;
+pub use models::*;
+pub use npn::*;
+pub use order::*;
+pub use parser::{parse, parse_parallel, parse_with_order};
+pub use quantify::*;
+pub use rows::*;
+pub use sensitivity::*;
+pub use spectrum::*;
+pub use substitute::*;
+pub use support::*;
+pub use symmetry::*;
 pub use token::Token;
 pub use utils::*;
 pub use table_parser::*;
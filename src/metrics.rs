@@ -0,0 +1,244 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, Circuit, GateKind, Signal, Token};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// a size and delay report of an expression (`T` is `Token`) or a netlist (`T` is `GateKind`)
+#[derive(PartialEq, Debug, Clone, Eq)]
+pub struct Metrics<T: Hash + Eq> {
+    /// the number of gates (operators) of every type
+    pub gates: HashMap<T, usize>,
+    /// the number of gate inputs that are variables
+    pub literals: usize,
+    /// the number of gates on the longest path from an input to an output
+    pub depth: usize,
+    /// the highest number of inputs of a gate
+    pub max_fan_in: usize,
+    /// the highest number of gates and outputs a variable or gate drives
+    pub max_fan_out: usize,
+    /// the variable at the start of the longest path (`None` if it starts at a constant)
+    pub critical_input: Option<String>,
+    /// the gates of the longest path, from the input to the output
+    pub critical_path: Vec<T>,
+}
+
+impl<T: Hash + Eq> Metrics<T> {
+    /// the number of all gates
+    pub fn gate_count(&self) -> usize {
+        self.gates.values().sum()
+    }
+}
+
+/// the metrics of an expression, every operator is a gate with the operator as its type
+///
+/// the expression is a tree so every operator drives exactly one other operator,
+/// only variables that appear more than once have a fan-out above 1
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b | !a & c
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Not,
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("c".to_string()),
+/// ];
+///
+/// let metrics = metrics(&func).unwrap();
+/// assert_eq!(metrics.gate_count(), 4);
+/// assert_eq!(metrics.gates[&Token::And], 2);
+/// assert_eq!(metrics.literals, 4);
+/// assert_eq!(metrics.depth, 3);
+/// assert_eq!(metrics.max_fan_out, 2);
+/// assert_eq!(metrics.critical_input, Some("a".to_string()));
+/// assert_eq!(metrics.critical_path, vec![Token::Not, Token::And, Token::Or]);
+/// ```
+pub fn metrics(func: &[Token]) -> Result<Metrics<Token>, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let expr = Expr::build(func, &names)?;
+
+    let mut metrics = Metrics {
+        gates: HashMap::new(),
+        literals: 0,
+        depth: 0,
+        max_fan_in: 0,
+        max_fan_out: 0,
+        critical_input: None,
+        critical_path: Vec::new(),
+    };
+    let mut occurrences = vec![0; names.len()];
+    count(&expr, &mut metrics, &mut occurrences);
+
+    let (leaf, mut path) = longest_path(&expr);
+    path.reverse();
+    metrics.depth = path.len();
+    metrics.critical_path = path;
+    metrics.critical_input = leaf.map(|index| names[index].clone());
+    // a gate drives its parent or the output
+    let gate_fan_out = if metrics.gates.is_empty() { 0 } else { 1 };
+    metrics.max_fan_out = occurrences.into_iter().max().unwrap_or(0).max(gate_fan_out);
+    Ok(metrics)
+}
+
+fn count(expr: &Expr, metrics: &mut Metrics<Token>, occurrences: &mut [usize]) {
+    let (operator, children) = match expr {
+        Expr::Const(_) => return,
+        Expr::Var(index) => {
+            occurrences[*index] += 1;
+            return;
+        }
+        Expr::Not(left) => (Token::Not, vec![&**left]),
+        Expr::Binary(operator, left, right) => (operator.clone(), vec![&**left, &**right]),
+    };
+
+    *metrics.gates.entry(operator).or_insert(0) += 1;
+    metrics.max_fan_in = metrics.max_fan_in.max(children.len());
+    for child in children {
+        if let Expr::Var(_) = child {
+            metrics.literals += 1;
+        }
+        count(child, metrics, occurrences);
+    }
+}
+
+/// the leaf at the end of the longest path (the first one if there are more)
+/// and the operators from the root to it
+fn longest_path(expr: &Expr) -> (Option<usize>, Vec<Token>) {
+    match expr {
+        Expr::Const(_) => (None, Vec::new()),
+        Expr::Var(index) => (Some(*index), Vec::new()),
+        Expr::Not(left) => {
+            let (leaf, mut path) = longest_path(left);
+            path.insert(0, Token::Not);
+            (leaf, path)
+        }
+        Expr::Binary(operator, left, right) => {
+            let left = longest_path(left);
+            let right = longest_path(right);
+            let (leaf, mut path) = if right.1.len() > left.1.len() {
+                right
+            } else {
+                left
+            };
+            path.insert(0, operator.clone());
+            (leaf, path)
+        }
+    }
+}
+
+impl Circuit {
+    /// the metrics of the netlist, shared gates are only counted once
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::*;
+    ///
+    /// // a full adder
+    /// let sum = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::Xor,
+    ///     Token::Var("b".to_string()),
+    ///     Token::Xor,
+    ///     Token::Var("c".to_string()),
+    /// ];
+    /// let carry = vec![
+    ///     Token::Var("a".to_string()),
+    ///     Token::And,
+    ///     Token::Var("b".to_string()),
+    ///     Token::Or,
+    ///     Token::Var("c".to_string()),
+    ///     Token::And,
+    ///     Token::Open,
+    ///     Token::Var("a".to_string()),
+    ///     Token::Xor,
+    ///     Token::Var("b".to_string()),
+    ///     Token::Close,
+    /// ];
+    ///
+    /// let metrics = Circuit::from_funcs(&[sum, carry]).unwrap().metrics();
+    /// // b ⊕ c, a ⊕ (b ⊕ c), a & b, a ⊕ b, c & (a ⊕ b), (a & b) | (c & (a ⊕ b))
+    /// assert_eq!(metrics.gates[&GateKind::Xor], 3);
+    /// assert_eq!(metrics.gate_count(), 6);
+    /// assert_eq!(metrics.depth, 3);
+    /// assert_eq!(metrics.critical_path, vec![GateKind::Xor, GateKind::And, GateKind::Or]);
+    /// ```
+    pub fn metrics(&self) -> Metrics<GateKind> {
+        let mut gates = HashMap::new();
+        let mut literals = 0;
+        let mut max_fan_in = 0;
+        let mut input_fan_out = vec![0; self.inputs().len()];
+        let mut gate_fan_out = vec![0; self.gates().len()];
+        let mut use_signal = |signal: &Signal| match signal {
+            Signal::Input(index) => input_fan_out[*index] += 1,
+            Signal::Gate(index) => gate_fan_out[*index] += 1,
+            Signal::Const(_) => (),
+        };
+
+        // the depth of every gate and the input of the gate on its longest path
+        let mut depths: Vec<(usize, Signal)> = Vec::with_capacity(self.gates().len());
+        let depth = |depths: &[(usize, Signal)], signal: &Signal| match signal {
+            Signal::Gate(index) => depths[*index].0,
+            _ => 0,
+        };
+        for gate in self.gates() {
+            *gates.entry(gate.kind).or_insert(0) += 1;
+            max_fan_in = max_fan_in.max(gate.inputs.len());
+            let mut longest = (0, gate.inputs[0]);
+            for signal in &gate.inputs {
+                use_signal(signal);
+                if let Signal::Input(_) = signal {
+                    literals += 1;
+                }
+                if depth(&depths, signal) > longest.0 {
+                    longest = (depth(&depths, signal), *signal);
+                }
+            }
+            depths.push((longest.0 + 1, longest.1));
+        }
+        for (_, signal) in self.outputs() {
+            use_signal(signal);
+        }
+
+        // walk back from the deepest output
+        let mut signal = self.outputs().iter().map(|(_, signal)| *signal).fold(
+            None,
+            |best: Option<Signal>, signal| match best {
+                Some(best) if depth(&depths, &best) >= depth(&depths, &signal) => Some(best),
+                _ => Some(signal),
+            },
+        );
+        let mut critical_path = Vec::new();
+        while let Some(Signal::Gate(index)) = signal {
+            critical_path.push(self.gates()[index].kind);
+            signal = Some(depths[index].1);
+        }
+        critical_path.reverse();
+        let critical_input = match signal {
+            Some(Signal::Input(index)) => Some(self.inputs()[index].clone()),
+            _ => None,
+        };
+
+        Metrics {
+            gates,
+            literals,
+            depth: critical_path.len(),
+            max_fan_in,
+            max_fan_out: input_fan_out
+                .into_iter()
+                .chain(gate_fan_out)
+                .max()
+                .unwrap_or(0),
+            critical_input,
+            critical_path,
+        }
+    }
+}
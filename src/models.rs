@@ -0,0 +1,275 @@
+use crate::cofactor::restrict_expr;
+use crate::parser::{apply, Expr};
+use crate::{get_names, validate_func, Token};
+use std::collections::HashMap;
+
+/// counts the assignments of the variables (of `get_names`) that make the function true
+///
+/// this doesn't build the table, it splits the function on variables (DPLL) and counts
+/// sub expressions that don't share any variables independently, the counts of sub expressions are cached
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // (a | b) & (c | d)
+/// let func = vec![
+///     Token::Open,
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Var("b".to_string()),
+///     Token::Close,
+///     Token::And,
+///     Token::Open,
+///     Token::Var("c".to_string()),
+///     Token::Or,
+///     Token::Var("d".to_string()),
+///     Token::Close,
+/// ];
+/// assert_eq!(count_models(&func), Ok(9));
+/// ```
+pub fn count_models(func: &[Token]) -> Result<u128, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    if names.len() >= u128::BITS as usize {
+        return Err(format!("too many variables {}", names.len()));
+    }
+
+    let expr = Expr::build(func, &names)?.fold();
+    let mut cache = HashMap::new();
+    let count = count(&expr, &mut cache);
+    Ok(count << (names.len() - vars(&expr).count_ones() as usize))
+}
+
+/// bitmask of all variables in the tree
+fn vars(expr: &Expr) -> u128 {
+    match expr {
+        Expr::Const(_) => 0,
+        Expr::Var(index) => 1 << index,
+        Expr::Not(left) => vars(left),
+        Expr::Binary(_, left, right) => vars(left) | vars(right),
+    }
+}
+
+/// number of models over the variables of the tree, the tree must be folded
+fn count(expr: &Expr, cache: &mut HashMap<Expr, u128>) -> u128 {
+    match expr {
+        Expr::Const(value) => return *value as u128,
+        Expr::Var(_) => return 1,
+        _ => (),
+    }
+    if let Some(&count) = cache.get(expr) {
+        return count;
+    }
+
+    let all = vars(expr);
+    let result = match expr {
+        Expr::Not(left) => (1 << all.count_ones()) - count(left, cache),
+        Expr::Binary(operator, left, right) if vars(left) & vars(right) == 0 => {
+            // independent components: count the combinations of both results
+            let true_left = count(left, cache);
+            let true_right = count(right, cache);
+            let false_left = (1 << vars(left).count_ones()) - true_left;
+            let false_right = (1 << vars(right).count_ones()) - true_right;
+
+            let mut result = 0;
+            for &(l, count_left) in [(false, false_left), (true, true_left)].iter() {
+                for &(r, count_right) in [(false, false_right), (true, true_right)].iter() {
+                    if apply(operator, l, r) {
+                        result += count_left * count_right;
+                    }
+                }
+            }
+            result
+        }
+        _ => {
+            let var = branch_var(expr);
+            let mut result = 0;
+            for &value in [false, true].iter() {
+                let restricted = restrict_expr(expr, var, value);
+                // variables that vanished in this branch can have any value
+                let vanished = (all & !(1 << var)).count_ones() - vars(&restricted).count_ones();
+                result += count(&restricted, cache) << vanished;
+            }
+            result
+        }
+    };
+
+    cache.insert(expr.clone(), result);
+    result
+}
+
+/// the variable that appears most often
+fn branch_var(expr: &Expr) -> usize {
+    fn occurrences(expr: &Expr, counts: &mut HashMap<usize, usize>) {
+        match expr {
+            Expr::Const(_) => (),
+            Expr::Var(index) => *counts.entry(*index).or_insert(0) += 1,
+            Expr::Not(left) => occurrences(left, counts),
+            Expr::Binary(_, left, right) => {
+                occurrences(left, counts);
+                occurrences(right, counts);
+            }
+        }
+    }
+
+    let mut counts = HashMap::new();
+    occurrences(expr, &mut counts);
+    counts
+        .into_iter()
+        .max_by_key(|&(index, count)| (count, std::cmp::Reverse(index)))
+        .map(|(index, _)| index)
+        .unwrap()
+}
+
+/// returns a lazy iterator over the satisfying assignments as cubes
+///
+/// a cube only contains the variables that are needed, every missing variable can have any value,
+/// the cubes don't overlap
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+/// use std::collections::HashMap;
+///
+/// // a | b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let cubes: Vec<HashMap<String, bool>> = model_cubes(&func).unwrap().collect();
+/// assert_eq!(cubes.len(), 2);
+/// assert_eq!(cubes[0].get("a"), Some(&false));
+/// assert_eq!(cubes[0].get("b"), Some(&true));
+/// assert_eq!(cubes[1].get("a"), Some(&true));
+/// assert_eq!(cubes[1].get("b"), None);
+/// ```
+pub fn model_cubes(func: &[Token]) -> Result<ModelCubes, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let expr = Expr::build(func, &names)?.fold();
+    let assignment = vec![None; names.len()];
+    Ok(ModelCubes {
+        names,
+        stack: vec![(expr, assignment)],
+    })
+}
+
+/// returns a lazy iterator over all satisfying assignments of the variables (of `get_names`)
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & !b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Not,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let models: Vec<_> = models(&func).unwrap().collect();
+/// assert_eq!(models.len(), 1);
+/// assert_eq!(models[0].get("a"), Some(&true));
+/// assert_eq!(models[0].get("b"), Some(&false));
+/// ```
+pub fn models(func: &[Token]) -> Result<Models, String> {
+    Ok(Models {
+        cubes: model_cubes(func)?,
+        current: None,
+    })
+}
+
+/// iterator over the satisfying cubes of a function, see `model_cubes`
+#[derive(Debug, Clone)]
+pub struct ModelCubes {
+    names: Vec<String>,
+    stack: Vec<(Expr, Vec<Option<bool>>)>,
+}
+
+impl ModelCubes {
+    /// the names of all variables of the function
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    fn next_cube(&mut self) -> Option<Vec<Option<bool>>> {
+        while let Some((expr, assignment)) = self.stack.pop() {
+            match expr {
+                Expr::Const(false) => continue,
+                Expr::Const(true) => return Some(assignment),
+                _ => {
+                    let var = branch_var(&expr);
+                    // push 1 first so the branch with 0 comes first
+                    for &value in [true, false].iter() {
+                        let mut assignment = assignment.clone();
+                        assignment[var] = Some(value);
+                        self.stack
+                            .push((restrict_expr(&expr, var, value), assignment));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for ModelCubes {
+    type Item = HashMap<String, bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cube = self.next_cube()?;
+        Some(
+            self.names
+                .iter()
+                .zip(cube)
+                .filter_map(|(name, value)| value.map(|value| (name.clone(), value)))
+                .collect(),
+        )
+    }
+}
+
+/// iterator over the satisfying assignments of a function, see `models`
+#[derive(Debug, Clone)]
+pub struct Models {
+    cubes: ModelCubes,
+    // the current cube, the indices of its free variables and the next combination of them
+    current: Option<(Vec<Option<bool>>, Vec<usize>, u128)>,
+}
+
+impl Iterator for Models {
+    type Item = HashMap<String, bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((cube, free, combination)) = &mut self.current {
+                if free.len() >= u128::BITS as usize || *combination < 1 << free.len() {
+                    let mut assignment = cube.clone();
+                    for (bit, &index) in free.iter().rev().enumerate() {
+                        assignment[index] = Some(*combination >> bit & 1 == 1);
+                    }
+                    *combination += 1;
+
+                    let names = self.cubes.names();
+                    return Some(
+                        names
+                            .iter()
+                            .zip(assignment)
+                            .map(|(name, value)| (name.clone(), value.unwrap()))
+                            .collect(),
+                    );
+                }
+            }
+
+            let cube = self.cubes.next_cube()?;
+            let free = (0..cube.len()).filter(|&i| cube[i].is_none()).collect();
+            self.current = Some((cube, free, 0));
+        }
+    }
+}
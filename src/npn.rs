@@ -0,0 +1,213 @@
+use crate::table_vars;
+
+/// a transform of the inputs and the output of a function:
+/// negation of inputs, permutation of inputs and negation of the output (NPN)
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct NpnTransform {
+    /// input `i` of the original function gets the value of input `permutation[i]` of the transformed function
+    pub permutation: Vec<usize>,
+    /// input `i` of the original function is negated
+    pub input_negation: Vec<bool>,
+    /// the result is negated
+    pub output_negation: bool,
+}
+
+impl NpnTransform {
+    /// the transform that doesn't change anything
+    pub fn identity(len: usize) -> Self {
+        Self {
+            permutation: (0..len).collect(),
+            input_negation: vec![false; len],
+            output_negation: false,
+        }
+    }
+
+    /// transforms a compressed table
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use bool_algebra::NpnTransform;
+    ///
+    /// // a & !b -> 0010
+    /// let table = vec![false, false, true, false];
+    ///
+    /// // f(y0, y1) = table(a = y1, b = !y0) = y1 & y0
+    /// let transform = NpnTransform {
+    ///     permutation: vec![1, 0],
+    ///     input_negation: vec![false, true],
+    ///     output_negation: false,
+    /// };
+    /// assert_eq!(transform.apply(&table), Ok(vec![false, false, false, true]));
+    /// ```
+    pub fn apply(&self, table: &[bool]) -> Result<Vec<bool>, String> {
+        let len = table_vars(table)?;
+        if self.permutation.len() != len || self.input_negation.len() != len {
+            return Err(format!(
+                "unexpected transform len, expected {} got {}",
+                len,
+                self.permutation.len()
+            ));
+        }
+        let mut sorted = self.permutation.clone();
+        sorted.sort_unstable();
+        if sorted.iter().enumerate().any(|(i, &p)| i != p) {
+            return Err("the permutation is invalid".to_string());
+        }
+        Ok(transform(
+            table,
+            len,
+            &self.permutation,
+            &self.input_negation,
+            self.output_negation,
+        ))
+    }
+}
+
+fn transform(
+    table: &[bool],
+    len: usize,
+    permutation: &[usize],
+    input_negation: &[bool],
+    output_negation: bool,
+) -> Vec<bool> {
+    (0..table.len())
+        .map(|row| {
+            let mut index = 0;
+            for i in 0..len {
+                let value = row >> (len - 1 - permutation[i]) & 1 == 1;
+                if value ^ input_negation[i] {
+                    index |= 1 << (len - 1 - i);
+                }
+            }
+            table[index] ^ output_negation
+        })
+        .collect()
+}
+
+/// computes the canonical representative of the NPN class of the function
+/// and the transform that turns the function into it
+///
+/// two functions are NPN equivalent if and only if they have the same canonical table,
+/// so the canonical table can be used as key of a `HashMap` to look up functions by their class
+///
+/// the canonical table is the lexicographically smallest table of all transforms,
+/// all `n! * 2^n` permutations and input negations are tried (the output negation follows from the first row) so it's only usable for a few variables
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & !b and !a | b are in the same class as a & b
+/// let (canonical, transform) = npn_canonical(&vec![false, false, true, false]).unwrap();
+/// assert_eq!(transform.apply(&vec![false, false, true, false]), Ok(canonical.clone()));
+/// assert_eq!(npn_canonical(&vec![true, true, false, true]).unwrap().0, canonical);
+/// assert_eq!(npn_canonical(&vec![false, false, false, true]).unwrap().0, canonical);
+///
+/// // a ⊕ b is not
+/// assert_ne!(npn_canonical(&vec![false, true, true, false]).unwrap().0, canonical);
+/// ```
+pub fn npn_canonical(table: &[bool]) -> Result<(Vec<bool>, NpnTransform), String> {
+    let len = table_vars(table)?;
+    let mut best = table.to_vec();
+    let mut best_transform = NpnTransform::identity(len);
+
+    let mut permutation: Vec<usize> = (0..len).collect();
+    loop {
+        for mask in 0..(1_usize << len) {
+            let input_negation: Vec<bool> = (0..len).map(|i| mask >> i & 1 == 1).collect();
+            let candidate = transform(table, len, &permutation, &input_negation, false);
+
+            // the smallest table starts with a zero, so negate the output if it doesn't
+            let output_negation = candidate[0];
+            let candidate = if output_negation {
+                candidate.iter().map(|b| !b).collect()
+            } else {
+                candidate
+            };
+
+            if candidate < best {
+                best = candidate;
+                best_transform = NpnTransform {
+                    permutation: permutation.clone(),
+                    input_negation,
+                    output_negation,
+                };
+            }
+        }
+        if !next_permutation(&mut permutation) {
+            break;
+        }
+    }
+    Ok((best, best_transform))
+}
+
+/// checks if two functions can be transformed into each other (see `npn_canonical`)
+///
+/// ## Example
+///
+/// ```rust
+/// // a | b and a & b
+/// assert_eq!(
+///     bool_algebra::npn_equivalent(&vec![false, true, true, true], &vec![false, false, false, true]),
+///     Ok(true)
+/// );
+/// ```
+pub fn npn_equivalent(a: &[bool], b: &[bool]) -> Result<bool, String> {
+    if a.len() != b.len() {
+        return Ok(false);
+    }
+    Ok(npn_canonical(a)?.0 == npn_canonical(b)?.0)
+}
+
+/// turns the permutation into the next one in lexicographic order,
+/// returns `false` if it was the last one
+///
+/// the permutations are generated one at a time because there are far too many to keep them all
+fn next_permutation(permutation: &mut [usize]) -> bool {
+    // the last position that is smaller than the one after it
+    let pivot = match (1..permutation.len())
+        .rev()
+        .find(|&i| permutation[i - 1] < permutation[i])
+    {
+        Some(i) => i - 1,
+        None => return false,
+    };
+    // the smallest larger value after it, the values after the pivot are descending
+    let swap = (pivot + 1..permutation.len())
+        .rev()
+        .find(|&i| permutation[i] > permutation[pivot])
+        .unwrap();
+    permutation.swap(pivot, swap);
+    permutation[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutations() {
+        let mut empty: Vec<usize> = vec![];
+        assert!(!next_permutation(&mut empty));
+
+        let mut permutation = vec![0, 1, 2];
+        let mut all = vec![permutation.clone()];
+        while next_permutation(&mut permutation) {
+            all.push(permutation.clone());
+        }
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0],
+            ]
+        );
+    }
+}
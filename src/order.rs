@@ -0,0 +1,168 @@
+use crate::{get_names, validate_tabel, Token};
+use std::cmp::Ordering;
+
+/// the order in which variable names get sorted
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
+pub enum NameOrder {
+    Alphabetical, // x1, x10, x2
+    Natural,      // x1, x2, x10
+}
+
+/// same as `get_names` but sorts the names in the given order
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // x10 & x2 | x1
+/// let func = vec![
+///     Token::Var("x10".to_string()),
+///     Token::And,
+///     Token::Var("x2".to_string()),
+///     Token::Or,
+///     Token::Var("x1".to_string()),
+/// ];
+/// assert_eq!(get_names_ordered(&func, NameOrder::Alphabetical), vec!["x1", "x10", "x2"]);
+/// assert_eq!(get_names_ordered(&func, NameOrder::Natural), vec!["x1", "x2", "x10"]);
+/// ```
+pub fn get_names_ordered(func: &[Token], order: NameOrder) -> Vec<String> {
+    let mut names = get_names(func);
+    if order == NameOrder::Natural {
+        names.sort_by(|a, b| natural_cmp(a, b));
+    }
+    names
+}
+
+/// compares two names but treats runs of digits as numbers so `x2` comes before `x10`
+///
+/// ## Example
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// assert_eq!(bool_algebra::natural_cmp("x2", "x10"), Ordering::Less);
+/// assert_eq!(bool_algebra::natural_cmp("a10b2", "a10b1"), Ordering::Greater);
+/// assert_eq!(bool_algebra::natural_cmp("b", "a1"), Ordering::Greater);
+/// // equal numbers with leading zeros are ordered like strings
+/// assert_eq!(bool_algebra::natural_cmp("x01", "x1"), Ordering::Less);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    // only names that are the same are equal
+    compare_numbers(a, b).then_with(|| a.cmp(b))
+}
+
+/// returns `Err` if a name appears more than once in the order
+pub(crate) fn validate_unique_names(order: &[String]) -> Result<(), String> {
+    for (i, name) in order.iter().enumerate() {
+        if order[..i].contains(name) {
+            return Err(format!(
+                "variable {} appears more than once in the order",
+                name
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+                // compare the length first so the numbers can't overflow
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(&y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.cmp(y);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+/// takes a run of digits without leading zeros
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut number = String::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        if !(number.is_empty() && c == '0') {
+            number.push(c);
+        }
+        chars.next();
+    }
+    number
+}
+
+/// reorders the variables of a compressed table
+///
+/// `names` is the order the table was created in and `order` the new order,
+/// both must contain the same names
+///
+/// ## Example
+///
+/// ```rust
+/// // a & !b with the order a, b -> 0010
+/// let table = vec![false, false, true, false];
+/// let names = vec!["a".to_string(), "b".to_string()];
+///
+/// // a & !b with the order b, a -> 0100
+/// let order = vec!["b".to_string(), "a".to_string()];
+/// assert_eq!(
+///     bool_algebra::permute_table(&table, &names, &order),
+///     Ok(vec![false, true, false, false])
+/// );
+/// ```
+pub fn permute_table(
+    table: &[bool],
+    names: &[String],
+    order: &[String],
+) -> Result<Vec<bool>, String> {
+    validate_tabel(table, names)?;
+    if order.len() != names.len() {
+        return Err(format!(
+            "unexpected order len, expected {} got {}",
+            names.len(),
+            order.len()
+        ));
+    }
+
+    validate_unique_names(order)?;
+
+    // position[i] is the position of order[i] in names
+    let mut position = Vec::with_capacity(order.len());
+    for name in order {
+        match names.iter().position(|n| n == name) {
+            Some(p) => position.push(p),
+            None => return Err(format!("variable {} is not in the table", name)),
+        }
+    }
+
+    let len = names.len();
+    let mut result = Vec::with_capacity(table.len());
+    for row in 0..table.len() {
+        let mut index = 0;
+        for (i, &p) in position.iter().enumerate() {
+            if row & (1 << (len - 1 - i)) != 0 {
+                index |= 1 << (len - 1 - p);
+            }
+        }
+        result.push(table[index]);
+    }
+    Ok(result)
+}
@@ -1,6 +1,6 @@
-use crate::{get_names, update_values, validate_func, Token};
-use std::collections::HashMap;
-use std::usize;
+use crate::order::validate_unique_names;
+use crate::{get_names, validate_func, CompiledExpr, Token};
+use std::thread;
 
 /// this is the main parse function
 ///  
@@ -38,49 +38,124 @@ use std::usize;
 ///
 /// assert_eq!(input, Ok(output));
 /// ```
-pub fn parse(func: &Vec<Token>) -> Result<Vec<bool>, String> {
+pub fn parse(func: &[Token]) -> Result<Vec<bool>, String> {
+    parse_with_order(func, &get_names(func))
+}
+
+/// same as `parse` but the table is created in the order of `names` instead of the order of `get_names`
+///
+/// the first name is the most significant bit of the row index,
+/// `names` can contain variables that don't appear in the function but must contain every variable of the function
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & !b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Not,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(parse(&func), Ok(vec![false, false, true, false]));
+///
+/// let names = vec!["b".to_string(), "a".to_string()];
+/// assert_eq!(parse_with_order(&func, &names), Ok(vec![false, true, false, false]));
+///
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+/// assert_eq!(
+///     parse_with_order(&func, &names),
+///     Ok(vec![false, false, false, false, true, true, false, false])
+/// );
+/// ```
+pub fn parse_with_order(func: &[Token], names: &[String]) -> Result<Vec<bool>, String> {
     validate_func(func)?;
+    validate_order(func, names)?;
+
+    let compiled = CompiledExpr::with_order(func, names)?;
+    if names.len() >= u64::BITS as usize {
+        return Err(format!("too many variables {}", names.len()));
+    }
 
-    let names = get_names(func);
     let len = usize::pow(2, names.len() as u32);
-    let mut values = Vec::with_capacity(len);
-    let mut lookup = HashMap::<Token, *mut bool>::with_capacity(len);
-    init_lookup_values(names, &mut lookup, &mut values);
+    Ok((0..len).map(|row| compiled.eval_bits(row as u64)).collect())
+}
+
+/// same as `parse` but the rows are split into `threads` chunks which are evaluated in parallel
+///
+/// the threads share the compiled function and the results are put together in order,
+/// there are never more threads than rows
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a ⊕ b ⊕ c
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Xor,
+///     Token::Var("b".to_string()),
+///     Token::Xor,
+///     Token::Var("c".to_string()),
+/// ];
+/// assert_eq!(parse_parallel(&func, 4), parse(&func));
+/// ```
+pub fn parse_parallel(func: &[Token], threads: usize) -> Result<Vec<bool>, String> {
+    if threads == 0 {
+        return Err("expected at least 1 thread".to_string());
+    }
+
+    let names = get_names(func);
+    let compiled = CompiledExpr::with_order(func, &names)?;
+    if names.len() >= u64::BITS as usize {
+        return Err(format!("too many variables {}", names.len()));
+    }
 
-    // this const bool is for the Token::One and Token::Zero thes valus don't get updated
-    let mut temp = vec![false, true];
-    let const_bool = unsafe { vec![temp.as_mut_ptr().add(0), temp.as_mut_ptr().add(1)] };
+    let len = usize::pow(2, names.len() as u32);
+    let chunk = len.div_ceil(threads.min(len));
+
+    let compiled = &compiled;
+    let chunks: Result<Vec<Vec<bool>>, String> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..len)
+            .step_by(chunk)
+            .map(|start| {
+                let end = usize::min(start + chunk, len);
+                scope.spawn(move || {
+                    (start..end)
+                        .map(|row| compiled.eval_bits(row as u64))
+                        .collect::<Vec<bool>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .map_err(|_| "a thread panicked while evaluating the function".to_string())
+            })
+            .collect()
+    });
 
-    let tree = Node::build_tree(func.clone(), &lookup, &const_bool);
     let mut result = Vec::with_capacity(len);
-
-    loop {
-        if let Some(value) = Node::eval(tree) {
-            result.push(value);
-        } else {
-            return Err(format!("something went wrong cannot evaluate expression"));
-        }
-        if !update_values(&mut values) {
-            return Ok(result);
-        }
+    for rows in chunks? {
+        result.extend(rows);
     }
+    Ok(result)
 }
 
-/// the lookup table is to build the tree if the node is a leaf node it stores a pointer to a boolean array
-/// this array gets updated with the function `update_values()`
-/// note tear are 2 constant values in the lookup table which don't change
-fn init_lookup_values(
-    names: Vec<String>,
-    lookup: &mut HashMap<Token, *mut bool>,
-    values: &mut Vec<bool>,
-) {
-    for name in names {
-        values.push(false);
-        unsafe {
-            let ptr = values.as_mut_ptr().add(values.len() - 1);
-            lookup.entry(Token::Var(name)).or_insert(ptr);
+/// every variable of the function must be in `names` and no name may appear twice
+fn validate_order(func: &[Token], names: &[String]) -> Result<(), String> {
+    validate_unique_names(names)?;
+    for name in get_names(func) {
+        if !names.contains(&name) {
+            return Err(format!("variable {} is missing in the order", name));
         }
     }
+    Ok(())
 }
 
 // low precedence -> high in the tree must be split first
@@ -105,7 +180,7 @@ fn precedence_of(bool_func: &Token) -> usize {
 }
 
 /// Example (a & b) -> true, (a) & (b) -> false
-fn is_parentheses(func: &Vec<Token>) -> bool {
+fn is_parentheses(func: &[Token]) -> bool {
     if func[0] != Token::Open {
         return false;
     }
@@ -114,14 +189,14 @@ fn is_parentheses(func: &Vec<Token>) -> bool {
     }
 
     let mut counter = 1;
-    for i in 1..(func.len() - 1) {
+    for token in &func[1..(func.len() - 1)] {
         if counter == 0 {
             return false;
         }
-        if func[i] == Token::Open {
+        if token == &Token::Open {
             counter += 1;
         }
-        if func[i] == Token::Close {
+        if token == &Token::Close {
             counter -= 1;
         }
     }
@@ -136,11 +211,8 @@ struct Bundle {
 }
 
 impl Bundle {
-    fn split_not(func: &Vec<Token>) -> Self {
-        let mut left = Vec::new();
-        for i in 1..func.len() {
-            left.push(func[i].clone());
-        }
+    fn split_not(func: &[Token]) -> Self {
+        let left = func[1..].to_vec();
 
         Self {
             left: Some(left),
@@ -149,7 +221,7 @@ impl Bundle {
         }
     }
 
-    fn split_operator(func: &Vec<Token>) -> Self {
+    fn split_operator(func: &[Token]) -> Self {
         let index = Self::split_index(func);
 
         // if it is 0 it must be not or an error
@@ -160,15 +232,9 @@ impl Bundle {
             unreachable!();
         }
 
-        let mut left = Vec::new();
-        let mut right = Vec::new();
+        let left = func[..index].to_vec();
+        let right = func[(index + 1)..].to_vec();
         let center = func[index].clone();
-        for i in 0..index {
-            left.push(func[i].clone());
-        }
-        for i in (index + 1)..func.len() {
-            right.push(func[i].clone());
-        }
 
         Self {
             left: Some(left),
@@ -179,7 +245,7 @@ impl Bundle {
 
     /// split_index: gives the index of the operator by which the expression must be split
     /// Exampel: "(a|b)&c" the function would return 5 witch is the index of the char '&'
-    fn split_index(func: &Vec<Token>) -> usize {
+    fn split_index(func: &[Token]) -> usize {
         let mut operator_index = 0;
         let mut operator_score = 0xff;
         let mut parentheses = 0;
@@ -198,8 +264,8 @@ impl Bundle {
                 parentheses += 1;
             }
 
-            if precedence_of(&t) < operator_score {
-                operator_score = precedence_of(&t);
+            if precedence_of(t) < operator_score {
+                operator_score = precedence_of(t);
                 operator_index = i;
                 if operator_score == 0 {
                     break;
@@ -207,12 +273,12 @@ impl Bundle {
             }
         }
 
-        return operator_index;
+        operator_index
     }
 
     /// this is a very imortant function to buid the tree. It splits the function in to 3 parts Example: a & b | (c | d) -> a & b, |, (c | d)
     /// this works with any arbitrarily complicated function
-    fn split(func: &Vec<Token>) -> Self {
+    fn split(func: &[Token]) -> Self {
         // is leavnode
         if func.len() == 1 {
             return Self {
@@ -226,246 +292,199 @@ impl Bundle {
             return Self::split_parentheses(func);
         }
 
-        return Self::split_operator(func);
+        Self::split_operator(func)
     }
 
     /// remove fist and last item and calls split
-    fn split_parentheses(func: &Vec<Token>) -> Self {
-        let mut result = Vec::new();
-        for i in 1..(func.len() - 1) {
-            result.push(func[i].clone());
-        }
-        return Self::split(&result);
+    fn split_parentheses(func: &[Token]) -> Self {
+        Self::split(&func[1..(func.len() - 1)])
     }
 }
 
-/// the node struct is for the tree
-#[derive(Debug)]
-struct Node {
-    left: Option<*mut Node>,
-    right: Option<*mut Node>,
-    value: Option<*mut bool>,
-    operator: Option<Token>,
+/// a safe version of the tree that owns its values
+/// leaf nodes store the index of the variable in the names instead of a pointer
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub(crate) enum Expr {
+    Const(bool),
+    Var(usize),
+    Not(Box<Expr>),
+    Binary(Token, Box<Expr>, Box<Expr>),
 }
 
-impl Node {
-    pub fn build_tree(
-        func: Vec<Token>,
-        lookup: &HashMap<Token, *mut bool>,
-        const_bool: &Vec<*mut bool>,
-    ) -> *mut Self {
-        let bundle = Bundle::split(&func);
-
-        // is binery operator (and, or, xor)
-        if let (Some(right), Some(left)) = (bundle.right.clone(), bundle.left.clone()) {
-            let node = Box::new(Node {
-                left: Some(Node::build_tree(left.clone(), lookup, const_bool)),
-                right: Some(Node::build_tree(right.clone(), lookup, const_bool)),
-                value: None,
-                operator: Some(bundle.center.clone()),
-            });
-
-            return Box::into_raw(node);
+impl Expr {
+    /// builds the tree from a valid function (see `validate_func`)
+    /// returns an error if a variable of the function is not in `names`
+    pub fn build(func: &[Token], names: &[String]) -> Result<Self, String> {
+        let bundle = Bundle::split(func);
+
+        match (bundle.left, bundle.right) {
+            (Some(_), Some(_)) if precedence_of(&bundle.center) > precedence_of(&Token::And) => {
+                Err(format!("expected operator got {}", bundle.center))
+            }
+            (Some(left), Some(right)) => Ok(Expr::Binary(
+                bundle.center,
+                Box::new(Expr::build(&left, names)?),
+                Box::new(Expr::build(&right, names)?),
+            )),
+            (Some(left), None) => Ok(Expr::Not(Box::new(Expr::build(&left, names)?))),
+            _ => match bundle.center {
+                Token::Zero => Ok(Expr::Const(false)),
+                Token::One => Ok(Expr::Const(true)),
+                Token::Var(name) => match names.iter().position(|n| n == &name) {
+                    Some(index) => Ok(Expr::Var(index)),
+                    None => Err(format!("variable {} is not bound", name)),
+                },
+                token => Err(format!("expected identifier got {}", token)),
+            },
         }
+    }
 
-        // is unery operator (not)
-        if let Some(left) = bundle.left.clone() {
-            let node = Box::new(Node {
-                left: Some(Node::build_tree(left.clone(), lookup, const_bool)),
-                right: None,
-                value: None,
-                operator: Some(bundle.center.clone()),
-            });
+    /// converts the tree back into a token stream with only the parentheses that are needed
+    pub fn to_tokens(&self, names: &[String]) -> Vec<Token> {
+        let mut func = Vec::new();
+        self.write_tokens(names, &mut func);
+        func
+    }
 
-            return Box::into_raw(node);
+    fn write_tokens(&self, names: &[String], func: &mut Vec<Token>) {
+        match self {
+            Expr::Const(true) => func.push(Token::One),
+            Expr::Const(false) => func.push(Token::Zero),
+            Expr::Var(index) => func.push(Token::Var(names[*index].clone())),
+            Expr::Not(left) => {
+                func.push(Token::Not);
+                left.write_child(names, func, left.precedence() < precedence_of(&Token::Not));
+            }
+            // the parser splits at the first operator with the lowest precedence
+            // so a chain of the same operator is read from the right: a & b & c -> a & (b & c)
+            Expr::Binary(operator, left, right) => {
+                let precedence = precedence_of(operator);
+                left.write_child(names, func, left.precedence() <= precedence);
+                func.push(operator.clone());
+                right.write_child(names, func, right.precedence() < precedence);
+            }
         }
+    }
 
-        // is leaf node
-        let value;
-        if bundle.center == Token::Zero {
-            value = Some(const_bool[0]);
-        } else if bundle.center == Token::One {
-            value = Some(const_bool[1]);
-        } else {
-            value = Some(*lookup.get(&bundle.center).unwrap());
+    fn write_child(&self, names: &[String], func: &mut Vec<Token>, parentheses: bool) {
+        if parentheses {
+            func.push(Token::Open);
+        }
+        self.write_tokens(names, func);
+        if parentheses {
+            func.push(Token::Close);
         }
-
-        let node = Box::new(Node {
-            left: None,
-            right: None,
-            value,
-            operator: None,
-        });
-        Box::into_raw(node)
     }
 
-    pub fn eval(node_ptr: *mut Node) -> Option<bool> {
-        // can do unsafe because node_ptr != null
-        unsafe {
-            // if levenode then return value from lookuptable
-            if let Some(value) = (*node_ptr).value {
-                return Some(*value);
-            }
+    fn precedence(&self) -> usize {
+        match self {
+            Expr::Const(_) | Expr::Var(_) => 0xff,
+            Expr::Not(_) => precedence_of(&Token::Not),
+            Expr::Binary(operator, _, _) => precedence_of(operator),
+        }
+    }
 
-            // if is not => unary operator only left side
-            if (*node_ptr).operator == Some(Token::Not) {
-                let left_node = (*node_ptr).left?;
-                let left = Node::eval(left_node)?;
-                return Some(!left);
-            }
+    /// replaces every variable with the tree returned by `f` for its index
+    pub fn map_vars<F: Fn(usize) -> Expr>(&self, f: &F) -> Expr {
+        match self {
+            Expr::Const(value) => Expr::Const(*value),
+            Expr::Var(index) => f(*index),
+            Expr::Not(left) => Expr::Not(Box::new(left.map_vars(f))),
+            Expr::Binary(operator, left, right) => Expr::Binary(
+                operator.clone(),
+                Box::new(left.map_vars(f)),
+                Box::new(right.map_vars(f)),
+            ),
+        }
+    }
 
-            // check if tree has left and right node get value recursively
-            // for pruning check right first sckause left is longer
-            let left_node = (*node_ptr).left?;
-            let right_node = (*node_ptr).right?;
-            let operator = (*node_ptr).operator.clone()?;
-            match operator {
-                Token::And => {
-                    let right = Node::eval(right_node)?;
-                    if right == false {
-                        return Some(false);
-                    } else {
-                        let left = Node::eval(left_node)?;
-                        return Some(left && right);
-                    }
-                }
-                Token::Or => {
-                    let right = Node::eval(right_node)?;
-                    if right == true {
-                        return Some(true);
-                    } else {
-                        let left = Node::eval(left_node)?;
-                        return Some(left || right);
-                    }
-                }
-                Token::Xor => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(left ^ right);
+    /// removes all constants from the tree (unless the whole tree is constant)
+    pub fn fold(self) -> Expr {
+        match self {
+            Expr::Not(left) => match left.fold() {
+                Expr::Const(value) => Expr::Const(!value),
+                Expr::Not(inner) => *inner,
+                left => Expr::Not(Box::new(left)),
+            },
+            Expr::Binary(operator, left, right) => match (operator, left.fold(), right.fold()) {
+                (operator, Expr::Const(left), Expr::Const(right)) => {
+                    Expr::Const(apply(&operator, left, right))
                 }
-                Token::Eq => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(!(left ^ right));
-                }
-                Token::ImplicAB => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(!left || right);
-                }
-                Token::ImplicBA => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(left || !right);
-                }
-                Token::Nand => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(!(left && right));
-                }
-                Token::Nor => {
-                    let right = Node::eval(right_node)?;
-                    let left = Node::eval(left_node)?;
-                    return Some(!(left || right));
-                }
-                Token::One => {
-                    return None;
-                }
-                Token::Not => {
-                    return None;
-                }
-                Token::Zero => {
-                    return None;
-                }
-                Token::Open => {
-                    return None;
-                }
-                Token::Close => {
-                    return None;
-                }
-                Token::Var(_) => {
-                    return None;
-                }
-            }
+                (operator, Expr::Const(left), right) => fold_const_left(&operator, left, right),
+                (operator, left, Expr::Const(right)) => fold_const_right(&operator, left, right),
+                (operator, left, right) => Expr::Binary(operator, Box::new(left), Box::new(right)),
+            },
+            expr => expr,
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// evaluates a single binary operator
+pub(crate) fn apply(operator: &Token, left: bool, right: bool) -> bool {
+    match operator {
+        Token::And => left && right,
+        Token::Or => left || right,
+        Token::Xor => left ^ right,
+        Token::Eq => !(left ^ right),
+        Token::ImplicAB => !left || right,
+        Token::ImplicBA => left || !right,
+        Token::Nand => !(left && right),
+        Token::Nor => !(left || right),
+        _ => unreachable!(),
+    }
+}
 
-    #[test]
-    fn test_eval() {
-        let func = vec![Token::Var("a".to_string())];
-        let mut values = Vec::<bool>::new();
-        let mut lookup = HashMap::<Token, *mut bool>::new();
-        init_lookup_values(get_names(&func), &mut lookup, &mut values);
-        let mut temp = vec![false, true];
-        let const_bool = unsafe { vec![temp.as_mut_ptr().add(0), temp.as_mut_ptr().add(1)] };
-        let tree = Node::build_tree(func.clone(), &lookup, &const_bool);
-        assert_eq!(Node::eval(tree), Some(false));
-        if let Some(ptr) = lookup.get(&Token::Var("a".to_string())) {
-            unsafe {
-                assert_eq!(format!("{:?}", ptr), format!("{:?}", values.as_ptr()));
-                assert_eq!(ptr.read_volatile(), false);
-            }
-        }
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(Node::eval(tree), Some(true));
-        if let Some(ptr) = lookup.get(&Token::Var("a".to_string())) {
-            unsafe {
-                assert_eq!(format!("{:?}", ptr), format!("{:?}", values.as_ptr()));
-                assert_eq!(ptr.read_volatile(), true);
-            }
-        }
-        assert_eq!(update_values(&mut values), false);
+/// `left` is constant and `right` is not
+fn fold_const_left(operator: &Token, left: bool, right: Expr) -> Expr {
+    match (operator, left) {
+        (Token::ImplicAB, false) => Expr::Const(true),
+        (Token::ImplicAB, true) => right,
+        (Token::ImplicBA, true) => Expr::Const(true),
+        (Token::ImplicBA, false) => Expr::Not(Box::new(right)).fold(),
+        // the other operators are commutative
+        _ => fold_const_right(operator, right, left),
     }
+}
 
-    #[test]
-    fn test_lookup_values_2() {
-        let func = vec![Token::Var("a".to_string()), Token::Var("b".to_string())];
-        let mut values = Vec::<bool>::new();
-        let mut lookup = HashMap::<Token, *mut bool>::new();
-        init_lookup_values(get_names(&func), &mut lookup, &mut values);
-
-        assert_eq!(values, vec![false, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![false, true]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, true]);
-        assert_eq!(update_values(&mut values), false);
+/// `right` is constant and `left` is not
+fn fold_const_right(operator: &Token, left: Expr, right: bool) -> Expr {
+    let not_left = || Expr::Not(Box::new(left.clone())).fold();
+    match (operator, right) {
+        (Token::And, false) | (Token::Nor, true) => Expr::Const(false),
+        (Token::Or, true) | (Token::Nand, false) => Expr::Const(true),
+        (Token::ImplicAB, true) | (Token::ImplicBA, false) => Expr::Const(true),
+        (Token::And, true) | (Token::Or, false) => left,
+        (Token::Xor, false) | (Token::Eq, true) | (Token::ImplicBA, true) => left,
+        (Token::Xor, true) | (Token::Eq, false) => not_left(),
+        (Token::Nand, true) | (Token::Nor, false) | (Token::ImplicAB, false) => not_left(),
+        _ => unreachable!(),
     }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::useless_vec)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_lookup_values_3() {
+    fn test_expr_build() {
+        // !a ⊕ 1
         let func = vec![
+            Token::Not,
             Token::Var("a".to_string()),
-            Token::Var("b".to_string()),
-            Token::Var("c".to_string()),
+            Token::Xor,
+            Token::One,
         ];
-        let mut values = Vec::<bool>::new();
-        let mut lookup = HashMap::<Token, *mut bool>::new();
-        init_lookup_values(get_names(&func), &mut lookup, &mut values);
-
-        assert_eq!(values, vec![false, false, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![false, false, true]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![false, true, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![false, true, true]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, false, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, false, true]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, true, false]);
-        assert_eq!(update_values(&mut values), true);
-        assert_eq!(values, vec![true, true, true]);
-        assert_eq!(update_values(&mut values), false);
+        let names = vec!["b".to_string(), "a".to_string()];
+        match Expr::build(&func, &names) {
+            Ok(Expr::Binary(Token::Xor, left, right)) => {
+                assert!(matches!(*left, Expr::Not(ref var) if matches!(**var, Expr::Var(1))));
+                assert!(matches!(*right, Expr::Const(true)));
+            }
+            expr => panic!("unexpected tree {:?}", expr),
+        }
+
+        assert!(Expr::build(&func, &["b".to_string()]).is_err());
     }
 
     #[test]
@@ -0,0 +1,139 @@
+use crate::cofactor::restrict_expr;
+use crate::parser::Expr;
+use crate::{get_names, table_cofactors, validate_func, validate_tabel, Token};
+
+/// existential quantification: eliminates every variable in `vars` with `f(var = 0) | f(var = 1)`
+///
+/// the result is true for an assignment of the remaining variables
+/// if there is any assignment of `vars` that makes the function true,
+/// variables that are not in the function are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b -> ∃b: a
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(exists(&func, &["b"]), Ok(vec![Token::Var("a".to_string())]));
+/// assert_eq!(exists(&func, &["a", "b"]), Ok(vec![Token::One]));
+/// ```
+pub fn exists(func: &[Token], vars: &[&str]) -> Result<Vec<Token>, String> {
+    quantify(func, vars, Token::Or)
+}
+
+/// universal quantification: eliminates every variable in `vars` with `f(var = 0) & f(var = 1)`
+///
+/// the result is true for an assignment of the remaining variables
+/// if every assignment of `vars` makes the function true,
+/// variables that are not in the function are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a | b -> ∀b: a
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(forall(&func, &["b"]), Ok(vec![Token::Var("a".to_string())]));
+/// assert_eq!(forall(&func, &["a", "b"]), Ok(vec![Token::Zero]));
+/// ```
+pub fn forall(func: &[Token], vars: &[&str]) -> Result<Vec<Token>, String> {
+    quantify(func, vars, Token::And)
+}
+
+fn quantify(func: &[Token], vars: &[&str], operator: Token) -> Result<Vec<Token>, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let mut expr = Expr::build(func, &names)?.fold();
+
+    for var in vars {
+        if let Some(index) = names.iter().position(|name| name == var) {
+            expr = Expr::Binary(
+                operator.clone(),
+                Box::new(restrict_expr(&expr, index, false)),
+                Box::new(restrict_expr(&expr, index, true)),
+            )
+            .fold();
+        }
+    }
+    Ok(expr.to_tokens(&names))
+}
+
+/// same as `exists` but for a compressed table
+///
+/// returns the table over the remaining names together with the names (in the same order as `names`),
+/// variables that are not in `names` are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// let table = vec![false, false, false, true];
+/// let names = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(
+///     bool_algebra::exists_table(&table, &names, &["a"]),
+///     Ok((vec![false, true], vec!["b".to_string()]))
+/// );
+/// ```
+pub fn exists_table(
+    table: &[bool],
+    names: &[String],
+    vars: &[&str],
+) -> Result<(Vec<bool>, Vec<String>), String> {
+    quantify_table(table, names, vars, |a, b| a || b)
+}
+
+/// same as `forall` but for a compressed table
+///
+/// returns the table over the remaining names together with the names (in the same order as `names`),
+/// variables that are not in `names` are ignored
+///
+/// ## Example
+///
+/// ```rust
+/// // a | b -> 0111
+/// let table = vec![false, true, true, true];
+/// let names = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(
+///     bool_algebra::forall_table(&table, &names, &["a"]),
+///     Ok((vec![false, true], vec!["b".to_string()]))
+/// );
+/// ```
+pub fn forall_table(
+    table: &[bool],
+    names: &[String],
+    vars: &[&str],
+) -> Result<(Vec<bool>, Vec<String>), String> {
+    quantify_table(table, names, vars, |a, b| a && b)
+}
+
+fn quantify_table<F: Fn(bool, bool) -> bool>(
+    table: &[bool],
+    names: &[String],
+    vars: &[&str],
+    combine: F,
+) -> Result<(Vec<bool>, Vec<String>), String> {
+    validate_tabel(table, names)?;
+    let mut table = table.to_vec();
+    let mut names = names.to_vec();
+
+    for var in vars {
+        // unknown variables and variables that were already eliminated don't change the table
+        if !names.iter().any(|name| name == var) {
+            continue;
+        }
+        let (f0, f1) = table_cofactors(&table, &names, var)?;
+        table = f0.iter().zip(f1).map(|(&a, b)| combine(a, b)).collect();
+        names.retain(|name| name != var);
+    }
+    Ok((table, names))
+}
@@ -0,0 +1,121 @@
+use crate::{CompiledExpr, Token};
+
+/// returns a lazy iterator over the rows of the table `parse` would generate
+///
+/// every item is the assignment of the variables (in the order of `get_names`) together with the result,
+/// the rows are only evaluated when they are needed and `skip` / `nth` jump directly to a row
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+///
+/// let mut rows = rows(&func).unwrap();
+/// assert_eq!(rows.next(), Some((vec![false, false], false)));
+/// assert_eq!(rows.nth(1), Some((vec![true, false], false)));
+/// assert_eq!(rows.next(), Some((vec![true, true], true)));
+/// assert_eq!(rows.next(), None);
+/// ```
+pub fn rows(func: &[Token]) -> Result<Rows, String> {
+    Rows::new(CompiledExpr::new(func)?)
+}
+
+/// iterator over the rows of a table, see `rows`
+#[derive(Debug, Clone)]
+pub struct Rows {
+    compiled: CompiledExpr,
+    front: usize,
+    back: usize,
+}
+
+impl Rows {
+    /// iterates over all rows of the compiled function,
+    /// the number of rows has to fit into a `usize`
+    pub fn new(compiled: CompiledExpr) -> Result<Self, String> {
+        if compiled.names().len() >= usize::BITS as usize {
+            return Err(format!("too many variables {}", compiled.names().len()));
+        }
+        let back = 1 << compiled.names().len();
+        Ok(Self {
+            compiled,
+            front: 0,
+            back,
+        })
+    }
+
+    /// the names of the variables in the order of the assignment
+    pub fn names(&self) -> &[String] {
+        self.compiled.names()
+    }
+
+    fn row(&self, index: usize) -> (Vec<bool>, bool) {
+        let len = self.names().len();
+        let assignment = (0..len).map(|i| index >> (len - 1 - i) & 1 == 1).collect();
+        (assignment, self.compiled.eval_bits(index as u64))
+    }
+}
+
+impl Iterator for Rows {
+    type Item = (Vec<bool>, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let row = self.row(self.front);
+        self.front += 1;
+        Some(row)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let remaining = self.back - self.front;
+        if n >= remaining {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n;
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Rows {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.row(self.back))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let remaining = self.back - self.front;
+        if n >= remaining {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n;
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for Rows {}
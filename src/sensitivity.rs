@@ -0,0 +1,164 @@
+use crate::cofactor::restrict_expr;
+use crate::parser::Expr;
+use crate::{get_names, table_cofactors, table_vars, validate_func, Token};
+
+/// the [boolean difference] `f(var = 0) ⊕ f(var = 1)` of the function
+///
+/// it's true for all assignments of the other variables where changing `var` changes the result
+///
+/// [boolean difference]:https://en.wikipedia.org/wiki/Boolean_differential_calculus
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b -> ∂/∂a = b
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("b".to_string()),
+/// ];
+/// assert_eq!(boolean_difference(&func, "a"), Ok(vec![Token::Var("b".to_string())]));
+/// ```
+pub fn boolean_difference(func: &[Token], var: &str) -> Result<Vec<Token>, String> {
+    validate_func(func)?;
+    let names = get_names(func);
+    let index = match names.iter().position(|name| name == var) {
+        Some(index) => index,
+        None => return Ok(vec![Token::Zero]),
+    };
+
+    let expr = Expr::build(func, &names)?;
+    let difference = Expr::Binary(
+        Token::Xor,
+        Box::new(restrict_expr(&expr, index, false)),
+        Box::new(restrict_expr(&expr, index, true)),
+    );
+    Ok(difference.fold().to_tokens(&names))
+}
+
+/// same as `boolean_difference` but for a compressed table
+///
+/// the result is a table over all names except `var` in the same order
+///
+/// ## Example
+///
+/// ```rust
+/// // a | b -> 0111, ∂/∂b = !a -> 10
+/// let table = vec![false, true, true, true];
+/// let names = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(
+///     bool_algebra::boolean_difference_table(&table, &names, "b"),
+///     Ok(vec![true, false])
+/// );
+/// ```
+pub fn boolean_difference_table(
+    table: &[bool],
+    names: &[String],
+    var: &str,
+) -> Result<Vec<bool>, String> {
+    let (f0, f1) = table_cofactors(table, names, var)?;
+    Ok(f0.iter().zip(f1).map(|(&a, b)| a ^ b).collect())
+}
+
+/// the influence of every variable (in the order of the table)
+///
+/// the influence is the fraction of rows where flipping the variable flips the result
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// let table = vec![false, false, false, true];
+/// assert_eq!(bool_algebra::influence(&table), Ok(vec![0.5, 0.5]));
+///
+/// // a -> 01
+/// assert_eq!(bool_algebra::influence(&vec![false, true]), Ok(vec![1.0]));
+/// ```
+pub fn influence(table: &[bool]) -> Result<Vec<f64>, String> {
+    let len = table_vars(table)?;
+    Ok((0..len)
+        .map(|var| {
+            let mask = 1 << (len - 1 - var);
+            let flips = (0..table.len())
+                .filter(|&i| table[i] != table[i ^ mask])
+                .count();
+            flips as f64 / table.len() as f64
+        })
+        .collect())
+}
+
+/// the sum of all influences, also known as the average sensitivity
+///
+/// ## Example
+///
+/// ```rust
+/// // a ⊕ b -> 0110
+/// assert_eq!(bool_algebra::total_influence(&vec![false, true, true, false]), Ok(2.0));
+/// ```
+pub fn total_influence(table: &[bool]) -> Result<f64, String> {
+    Ok(influence(table)?.iter().sum())
+}
+
+/// the sensitivity is the highest number of variables that flip the result on their own, for any row
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001, in row 11 both variables change the result
+/// assert_eq!(bool_algebra::sensitivity(&vec![false, false, false, true]), Ok(2));
+/// ```
+pub fn sensitivity(table: &[bool]) -> Result<usize, String> {
+    let len = table_vars(table)?;
+    Ok((0..table.len())
+        .map(|i| {
+            (0..len)
+                .filter(|bit| table[i] != table[i ^ (1 << bit)])
+                .count()
+        })
+        .max()
+        .unwrap_or(0))
+}
+
+/// the block sensitivity is the highest number of disjoint sets of variables (blocks)
+/// where flipping all variables of a block flips the result, for any row
+///
+/// this is always at least the sensitivity, note it has to try all blocks so it's only usable for small tables
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// assert_eq!(bool_algebra::block_sensitivity(&vec![false, false, false, true]), Ok(2));
+/// ```
+pub fn block_sensitivity(table: &[bool]) -> Result<usize, String> {
+    table_vars(table)?;
+    let mut best = 0;
+    // blocks[mask] is the number of disjoint sensitive blocks that fit in mask
+    let mut blocks = vec![0; table.len()];
+
+    for row in 0..table.len() {
+        for mask in 1..table.len() {
+            let lowest = mask & mask.wrapping_neg();
+            // either the lowest bit is in no block
+            let mut count = blocks[mask ^ lowest];
+            // or it's in a sensitive block that is a subset of mask
+            let rest = mask ^ lowest;
+            let mut sub = rest;
+            loop {
+                let block = sub | lowest;
+                if table[row ^ block] != table[row] {
+                    count = usize::max(count, 1 + blocks[mask ^ block]);
+                }
+                if sub == 0 {
+                    break;
+                }
+                sub = (sub - 1) & rest;
+            }
+            blocks[mask] = count;
+        }
+        best = usize::max(best, blocks[table.len() - 1]);
+    }
+    Ok(best)
+}
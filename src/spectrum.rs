@@ -0,0 +1,153 @@
+use crate::table_vars;
+
+/// the [Walsh spectrum] of a compressed table computed with the fast Walsh–Hadamard transform
+///
+/// `W(a) = Σ (-1)^(f(x) ⊕ a·x)` where `a·x` is the parity of the variables set in both `a` and `x`,
+/// the index `a` uses the same bit order as the rows of the table
+///
+/// [Walsh spectrum]:https://en.wikipedia.org/wiki/Hadamard_transform
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// let table = vec![false, false, false, true];
+/// assert_eq!(bool_algebra::walsh_spectrum(&table), Ok(vec![2, 2, 2, -2]));
+/// ```
+pub fn walsh_spectrum(table: &[bool]) -> Result<Vec<i64>, String> {
+    table_vars(table)?;
+    let mut spectrum: Vec<i64> = table.iter().map(|&b| if b { -1 } else { 1 }).collect();
+
+    let mut step = 1;
+    while step < spectrum.len() {
+        for start in (0..spectrum.len()).step_by(step * 2) {
+            for i in start..(start + step) {
+                let (a, b) = (spectrum[i], spectrum[i + step]);
+                spectrum[i] = a + b;
+                spectrum[i + step] = a - b;
+            }
+        }
+        step *= 2;
+    }
+    Ok(spectrum)
+}
+
+/// the [autocorrelation] spectrum `r(d) = Σ (-1)^(f(x) ⊕ f(x ⊕ d))`
+///
+/// [autocorrelation]:https://en.wikipedia.org/wiki/Autocorrelation
+///
+/// ## Example
+///
+/// ```rust
+/// // a ⊕ b -> 0110
+/// let table = vec![false, true, true, false];
+/// assert_eq!(bool_algebra::autocorrelation(&table), Ok(vec![4, -4, -4, 4]));
+/// ```
+pub fn autocorrelation(table: &[bool]) -> Result<Vec<i64>, String> {
+    table_vars(table)?;
+    Ok((0..table.len())
+        .map(|d| {
+            (0..table.len())
+                .map(|x| if table[x] == table[x ^ d] { 1 } else { -1 })
+                .sum()
+        })
+        .collect())
+}
+
+/// the distance to the closest affine function: `2^(n-1) - max|W(a)| / 2`
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// assert_eq!(bool_algebra::nonlinearity(&vec![false, false, false, true]), Ok(1));
+///
+/// // a ⊕ b -> 0110 is linear
+/// assert_eq!(bool_algebra::nonlinearity(&vec![false, true, true, false]), Ok(0));
+/// ```
+pub fn nonlinearity(table: &[bool]) -> Result<usize, String> {
+    let spectrum = walsh_spectrum(table)?;
+    let max = spectrum.iter().map(|w| w.abs()).max().unwrap_or(0) as usize;
+    Ok((table.len() - max) / 2)
+}
+
+/// a function is bent if it has the highest possible nonlinearity,
+/// which is only possible for an even number of variables where every `|W(a)| = 2^(n/2)`
+///
+/// ## Example
+///
+/// ```rust
+/// // a & b -> 0001
+/// assert_eq!(bool_algebra::is_bent(&vec![false, false, false, true]), Ok(true));
+/// assert_eq!(bool_algebra::is_bent(&vec![false, true, true, false]), Ok(false));
+/// ```
+pub fn is_bent(table: &[bool]) -> Result<bool, String> {
+    let len = table_vars(table)?;
+    if len % 2 != 0 {
+        return Ok(false);
+    }
+    let expected = 1_i64 << (len / 2);
+    Ok(walsh_spectrum(table)?.iter().all(|w| w.abs() == expected))
+}
+
+/// a function is balanced if it has as many ones as zeros
+///
+/// ## Example
+///
+/// ```rust
+/// assert_eq!(bool_algebra::is_balanced(&vec![false, true, true, false]), Ok(true));
+/// assert_eq!(bool_algebra::is_balanced(&vec![false, false, false, true]), Ok(false));
+/// ```
+pub fn is_balanced(table: &[bool]) -> Result<bool, String> {
+    table_vars(table)?;
+    Ok(table.iter().filter(|&&b| b).count() * 2 == table.len())
+}
+
+/// the [correlation immunity] order: the highest `m` so that `W(a) = 0` for all `a` with `1 <= weight(a) <= m`
+///
+/// the result doesn't change if any `m` or fewer variables are fixed
+///
+/// [correlation immunity]:https://en.wikipedia.org/wiki/Correlation_immunity
+///
+/// ## Example
+///
+/// ```rust
+/// // a ⊕ b ⊕ c
+/// let table = vec![false, true, true, false, true, false, false, true];
+/// assert_eq!(bool_algebra::correlation_immunity(&table), Ok(2));
+///
+/// // a & b
+/// assert_eq!(bool_algebra::correlation_immunity(&vec![false, false, false, true]), Ok(0));
+/// ```
+pub fn correlation_immunity(table: &[bool]) -> Result<usize, String> {
+    let len = table_vars(table)?;
+    let spectrum = walsh_spectrum(table)?;
+
+    // the lowest weight of a non zero coefficient (except W(0)) limits the order
+    let order = (1..spectrum.len())
+        .filter(|&a| spectrum[a] != 0)
+        .map(|a| a.count_ones() as usize - 1)
+        .min()
+        .unwrap_or(len);
+    Ok(order)
+}
+
+/// the resiliency order is the correlation immunity order of a balanced function
+/// or `None` if the function isn't balanced
+///
+/// ## Example
+///
+/// ```rust
+/// // a ⊕ b ⊕ c
+/// let table = vec![false, true, true, false, true, false, false, true];
+/// assert_eq!(bool_algebra::resiliency(&table), Ok(Some(2)));
+///
+/// // a & b
+/// assert_eq!(bool_algebra::resiliency(&vec![false, false, false, true]), Ok(None));
+/// ```
+pub fn resiliency(table: &[bool]) -> Result<Option<usize>, String> {
+    if !is_balanced(table)? {
+        return Ok(None);
+    }
+    Ok(Some(correlation_immunity(table)?))
+}
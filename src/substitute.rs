@@ -0,0 +1,95 @@
+use crate::parser::Expr;
+use crate::{get_names, validate_func, Token};
+use std::collections::HashMap;
+
+/// replaces every `Token::Var(var)` in the function with `replacement`
+///
+/// parentheses are added where they are needed so the precedence of the replacement doesn't change
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & c with a = b | c -> (b | c) & c
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::And,
+///     Token::Var("c".to_string()),
+/// ];
+/// let replacement = vec![
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Var("c".to_string()),
+/// ];
+/// let result = vec![
+///     Token::Open,
+///     Token::Var("b".to_string()),
+///     Token::Or,
+///     Token::Var("c".to_string()),
+///     Token::Close,
+///     Token::And,
+///     Token::Var("c".to_string()),
+/// ];
+/// assert_eq!(substitute(&func, "a", &replacement), Ok(result));
+/// ```
+pub fn substitute(func: &[Token], var: &str, replacement: &[Token]) -> Result<Vec<Token>, String> {
+    let mut substitutions = HashMap::new();
+    substitutions.insert(var.to_string(), replacement.to_vec());
+    compose(func, &substitutions)
+}
+
+/// replaces all variables in `substitutions` at the same time
+///
+/// the replacements are not substituted into each other, so variables can be swapped or renamed
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+/// use std::collections::HashMap;
+///
+/// // a → b with a = b and b = a -> b → a
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::ImplicAB,
+///     Token::Var("b".to_string()),
+/// ];
+/// let mut substitutions = HashMap::new();
+/// substitutions.insert("a".to_string(), vec![Token::Var("b".to_string())]);
+/// substitutions.insert("b".to_string(), vec![Token::Var("a".to_string())]);
+///
+/// let result = vec![
+///     Token::Var("b".to_string()),
+///     Token::ImplicAB,
+///     Token::Var("a".to_string()),
+/// ];
+/// assert_eq!(compose(&func, &substitutions), Ok(result));
+/// ```
+pub fn compose(
+    func: &[Token],
+    substitutions: &HashMap<String, Vec<Token>>,
+) -> Result<Vec<Token>, String> {
+    validate_func(func)?;
+
+    // every tree uses the same names so the indices match after the substitution
+    let mut all = func.to_vec();
+    for replacement in substitutions.values() {
+        validate_func(replacement)?;
+        all.extend(replacement.iter().cloned());
+    }
+    let names = get_names(&all);
+
+    let mut replacements = HashMap::new();
+    for (var, replacement) in substitutions {
+        if let Some(index) = names.iter().position(|name| name == var) {
+            replacements.insert(index, Expr::build(replacement, &names)?);
+        }
+    }
+
+    let expr = Expr::build(func, &names)?.map_vars(&|index| match replacements.get(&index) {
+        Some(replacement) => replacement.clone(),
+        None => Expr::Var(index),
+    });
+    Ok(expr.to_tokens(&names))
+}
@@ -0,0 +1,102 @@
+use crate::{get_names, parse, validate_tabel, Token};
+
+/// returns the variables the function actually depends on in the same order as `get_names`
+///
+/// unlike `get_names` this ignores variables that can't change the result
+///
+/// func: `a | (b & !b)` -> `vec["a"]`
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a | (b & !b) -> vec["a"]
+///
+/// let func = vec![
+///     Token::Var("a".to_string()),
+///     Token::Or,
+///     Token::Open,
+///     Token::Var("b".to_string()),
+///     Token::And,
+///     Token::Not,
+///     Token::Var("b".to_string()),
+///     Token::Close,
+/// ];
+/// assert_eq!(get_names(&func), vec!["a".to_string(), "b".to_string()]);
+/// assert_eq!(support(&func), Ok(vec!["a".to_string()]));
+/// ```
+pub fn support(func: &[Token]) -> Result<Vec<String>, String> {
+    let table = parse(func)?;
+    let (_, names) = project_table(&table, &get_names(func))?;
+    Ok(names)
+}
+
+/// removes all irrelevant variables from a compressed table
+/// and returns the smaller table together with the remaining names
+///
+/// ## Example
+///
+/// ```rust
+/// // a b c | result          a c | result
+/// // 0 0 0 |   0             0 0 |   0
+/// // 0 0 1 |   1             0 1 |   1
+/// // 0 1 0 |   0     =>      1 0 |   1
+/// // 0 1 1 |   1             1 1 |   1
+/// // 1 x x |   ..
+///
+/// let table = vec![false, true, false, true, true, true, true, true];
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+/// assert_eq!(
+///     bool_algebra::project_table(&table, &names),
+///     Ok((
+///         vec![false, true, true, true],
+///         vec!["a".to_string(), "c".to_string()]
+///     ))
+/// );
+/// ```
+pub fn project_table(table: &[bool], names: &[String]) -> Result<(Vec<bool>, Vec<String>), String> {
+    validate_tabel(table, names)?;
+
+    let len = names.len();
+    let essential: Vec<usize> = (0..len).filter(|&i| depends_on(table, len, i)).collect();
+
+    let mut projected = Vec::with_capacity(1 << essential.len());
+    for row in 0..(1 << essential.len()) {
+        // the irrelevant variables stay 0
+        let mut index = 0;
+        for (bit, &var) in essential.iter().rev().enumerate() {
+            if row & (1 << bit) != 0 {
+                index |= 1 << (len - 1 - var);
+            }
+        }
+        projected.push(table[index]);
+    }
+
+    let names = essential.iter().map(|&i| names[i].clone()).collect();
+    Ok((projected, names))
+}
+
+/// checks if flipping the variable at `var` (index in the names) changes the result for any row
+fn depends_on(table: &[bool], len: usize, var: usize) -> bool {
+    let mask = 1 << (len - 1 - var);
+    (0..table.len()).any(|i| i & mask == 0 && table[i] != table[i | mask])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depends_on() {
+        // a & b
+        let table = [false, false, false, true];
+        assert!(depends_on(&table, 2, 0));
+        assert!(depends_on(&table, 2, 1));
+
+        // b
+        let table = [false, true, false, true];
+        assert!(!depends_on(&table, 2, 0));
+        assert!(depends_on(&table, 2, 1));
+    }
+}
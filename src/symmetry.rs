@@ -0,0 +1,127 @@
+use crate::{dnf, validate_tabel, Token};
+
+/// the symmetries of a function, see `symmetries`
+#[derive(PartialEq, Debug, Clone, Eq, Hash)]
+pub struct Symmetries {
+    /// the result only depends on the number of ones (every pair is symmetric)
+    pub total: bool,
+    /// pairs of variables that can be swapped: f(.., a, .., b, ..) = f(.., b, .., a, ..)
+    pub symmetric: Vec<(String, String)>,
+    /// pairs of variables that can be swapped and negated: f(.., a, .., b, ..) = f(.., !b, .., !a, ..)
+    pub anti_symmetric: Vec<(String, String)>,
+}
+
+/// finds all symmetric and anti-symmetric pairs of variables of a compressed table
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// // a & b ⊕ c -> 0101 0110
+/// let table = vec![false, true, false, true, false, true, true, false];
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+///
+/// let symmetries = symmetries(&table, &names).unwrap();
+/// assert_eq!(symmetries.total, false);
+/// assert_eq!(symmetries.symmetric, vec![("a".to_string(), "b".to_string())]);
+/// assert_eq!(symmetries.anti_symmetric, vec![]);
+/// ```
+pub fn symmetries(table: &[bool], names: &[String]) -> Result<Symmetries, String> {
+    validate_tabel(table, names)?;
+    let len = names.len();
+
+    let mut symmetric = Vec::new();
+    let mut anti_symmetric = Vec::new();
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let (mask_i, mask_j) = (1 << (len - 1 - i), 1 << (len - 1 - j));
+            let pair = (names[i].clone(), names[j].clone());
+
+            // swapping only changes the rows where the values are different
+            if (0..table.len())
+                .filter(|x| x & mask_i != 0 && x & mask_j == 0)
+                .all(|x| table[x] == table[x ^ mask_i ^ mask_j])
+            {
+                symmetric.push(pair.clone());
+            }
+
+            // swapping and negating only changes the rows where the values are equal
+            if (0..table.len())
+                .filter(|x| x & mask_i == 0 && x & mask_j == 0)
+                .all(|x| table[x] == table[x ^ mask_i ^ mask_j])
+            {
+                anti_symmetric.push(pair);
+            }
+        }
+    }
+
+    Ok(Symmetries {
+        total: symmetric.len() == len * len.saturating_sub(1) / 2,
+        symmetric,
+        anti_symmetric,
+    })
+}
+
+/// generates the table of a totally symmetric function with `len` variables
+/// that is true if the number of ones is in `weights`
+///
+/// ## Example
+///
+/// ```rust
+/// // exactly one of a, b -> 0110
+/// assert_eq!(bool_algebra::symmetric_table(2, &[1]), vec![false, true, true, false]);
+/// ```
+pub fn symmetric_table(len: usize, weights: &[usize]) -> Vec<bool> {
+    (0..(1_usize << len))
+        .map(|row| weights.contains(&(row.count_ones() as usize)))
+        .collect()
+}
+
+/// same as `symmetric_table` but returns the function (in disjunctive normal form)
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// let names = vec!["a".to_string(), "b".to_string()];
+/// let func = symmetric_func(&names, &[0, 2]).unwrap();
+/// assert_eq!(parse(&func), Ok(vec![true, false, false, true]));
+/// ```
+pub fn symmetric_func(names: &[String], weights: &[usize]) -> Result<Vec<Token>, String> {
+    let table = symmetric_table(names.len(), weights);
+    if table.iter().all(|&b| !b) {
+        return Ok(vec![Token::Zero]);
+    }
+    if table.iter().all(|&b| b) {
+        return Ok(vec![Token::One]);
+    }
+    dnf(&table, names, true)
+}
+
+/// true if more than half of the variables are true
+///
+/// ## Example
+///
+/// ```rust
+/// use bool_algebra::*;
+///
+/// let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+/// let func = majority(&names).unwrap();
+/// assert_eq!(parse(&func), Ok(vec![false, false, false, true, false, true, true, true]));
+/// ```
+pub fn majority(names: &[String]) -> Result<Vec<Token>, String> {
+    threshold(names, names.len() / 2 + 1)
+}
+
+/// true if at least `k` variables are true
+pub fn threshold(names: &[String], k: usize) -> Result<Vec<Token>, String> {
+    let weights: Vec<usize> = (k..=names.len()).collect();
+    symmetric_func(names, &weights)
+}
+
+/// true if exactly `k` variables are true
+pub fn exactly(names: &[String], k: usize) -> Result<Vec<Token>, String> {
+    symmetric_func(names, &[k])
+}
@@ -27,7 +27,7 @@ pub fn parse_full(
         return Err("incorrect table shape".to_string());
     }
   
-    return parse_fill(in_len, out_len, table, false);
+    parse_fill(in_len, out_len, table, false)
 }
 
 pub fn parse_count(
@@ -112,8 +112,8 @@ fn bool2_int(vec: Vec<bool>) -> usize {
 fn match_line(vec: Vec<bool>, len: usize) -> (usize, Vec<bool>) {
     let mut temp = Vec::new();
 
-    for i in 0..(vec.len() - len) {
-        temp.push(vec[i]);
+    for &b in &vec[..(vec.len() - len)] {
+        temp.push(b);
     }
 
     let index = bool2_int(temp);
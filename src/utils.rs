@@ -1,5 +1,4 @@
 use crate::Token;
-use std::usize;
 
 /// parses all unique var names in the token stream (`func: &Vec<Token>`) and returns it in the same order the table was created
 ///
@@ -22,23 +21,20 @@ use std::usize;
 /// let output = vec!["a".to_string(), "b".to_string()];
 /// assert_eq!(get_names(&input), output);
 /// ```
-pub fn get_names(func: &Vec<Token>) -> Vec<String> {
+pub fn get_names(func: &[Token]) -> Vec<String> {
     let mut vars = Vec::new();
     for f in func {
-        match f.clone() {
-            Token::Var(name) => {
-                let mut in_var = false;
-                for s in vars.clone() {
-                    if s == name {
-                        in_var = true;
-                        break;
-                    }
-                }
-                if !in_var {
-                    vars.push(name);
+        if let Token::Var(name) = f.clone() {
+            let mut in_var = false;
+            for s in vars.clone() {
+                if s == name {
+                    in_var = true;
+                    break;
                 }
             }
-            _ => (),
+            if !in_var {
+                vars.push(name);
+            }
         }
     }
     vars.sort();
@@ -47,7 +43,7 @@ pub fn get_names(func: &Vec<Token>) -> Vec<String> {
 
 /// checks if the input function is a valid expression
 /// returns Ok(()) if it's okay and Err(String) with an error message
-pub fn validate_func(func: &Vec<Token>) -> Result<(), String> {
+pub fn validate_func(func: &[Token]) -> Result<(), String> {
     // increments on '(' and decrements on ')' should never be -1. Exampel: (a) & b ) is invalid
     let mut count_parentheses = 0;
     // counts all binary operator (and, or, xor) and cheks if ther are enough identifiers. Exampel: !a & & b is invalid
@@ -129,21 +125,22 @@ pub fn validate_func(func: &Vec<Token>) -> Result<(), String> {
     }
 
     if count_binary != count_identifier - 1 {
-        return Err(format!(
-            "number of identifier doesn't match with the number of the binary operators"
-        ));
+        return Err(
+            "number of identifier doesn't match with the number of the binary operators".to_string(),
+        );
     }
     if count_parentheses != 0 {
-        return Err(format!(
+        return Err(
             "number of open parentheses doesn't match with the number of closing parentheses"
-        ));
+                .to_string(),
+        );
     }
     Ok(())
 }
 
 /// checks if the input table is a valid
 /// returns Ok(()) if it's okay and Err(String) with an error message
-pub fn validate_tabel(table: &Vec<bool>, names: &Vec<String>) -> Result<(), String> {
+pub fn validate_tabel(table: &[bool], names: &[String]) -> Result<(), String> {
     if table.len() != usize::pow(2, names.len() as u32) {
         Err(format!(
             "unexpected table len, expected 2^{} = {} got {}",
@@ -157,12 +154,12 @@ pub fn validate_tabel(table: &Vec<bool>, names: &Vec<String>) -> Result<(), Stri
 }
 
 /// prints a functon
-pub fn print_func(func: &Vec<Token>) -> String {
+pub fn print_func(func: &[Token]) -> String {
     let mut out = String::new();
     for token in func {
         out.push_str(&format!("{} ", token));
     }
-    out.push_str("\n");
+    out.push('\n');
     out
 }
 
@@ -174,7 +171,7 @@ pub fn print_func(func: &Vec<Token>) -> String {
 /// ```rust
 /// let table = vec![false, false, false, true];
 /// let names = vec!["a".to_string(), "b".to_string()];
-/// let output = bool_algebra::print_tabel(&table, &names, &"result".to_string());
+/// let output = bool_algebra::print_tabel(&table, &names, "result");
 /// let lines = vec![
 ///     "a b | result",
 ///     "0 0 |   0",
@@ -184,12 +181,12 @@ pub fn print_func(func: &Vec<Token>) -> String {
 /// ];
 /// assert_eq!(output, lines.join("\n"));
 /// ```
-pub fn print_tabel(table: &Vec<bool>, names: &Vec<String>, result_name: &String) -> String {
+pub fn print_tabel(table: &[bool], names: &[String], result_name: &str) -> String {
     if let Err(err) = validate_tabel(table, names) {
-        return format!("{}", err);
+        return err.to_string();
     }
 
-    fn get_offset(name: &String) -> (usize, usize) {
+    fn get_offset(name: &str) -> (usize, usize) {
         let len = name.len() - 1;
         let right = len / 2;
         let left = len - right;
@@ -219,7 +216,7 @@ pub fn print_tabel(table: &Vec<bool>, names: &Vec<String>, result_name: &String)
     }
     out.push_str(&format!("| {}\n", result_name));
 
-    let offset: Vec<(usize, usize)> = names.iter().map(get_offset).collect();
+    let offset: Vec<(usize, usize)> = names.iter().map(|name| get_offset(name)).collect();
     let result_offset = get_offset(result_name).1;
 
     let mut values = vec![false; names.len()];
@@ -230,7 +227,7 @@ pub fn print_tabel(table: &Vec<bool>, names: &Vec<String>, result_name: &String)
             out.push_str(&print_offset(offset[i], value));
             out.push(' ');
         }
-        out.push_str(&format!("| "));
+        out.push_str("| ");
         for _ in 0..result_offset {
             out.push(' ');
         }
@@ -261,14 +258,14 @@ pub fn print_tabel(table: &Vec<bool>, names: &Vec<String>, result_name: &String)
 /// assert_eq!(bool_algebra::update_values(&mut values), true);
 /// assert_eq!(values, vec![false, true ,true]);
 /// ```
-pub fn update_values(values: &mut Vec<bool>) -> bool {
+pub fn update_values(values: &mut [bool]) -> bool {
     for i in (0..values.len()).rev() {
         values[i] = !values[i];
-        if values[i].clone() == true {
+        if values[i] {
             return true;
         }
     }
-    return false;
+    false
 }
 
 /// generates a table_2d that counts in binary form 0 until every bit is one
@@ -316,3 +313,22 @@ pub fn bool_to_u32(binary: Vec<bool>) -> u32 {
     }
     num
 }
+
+/// returns the number of variables of a compressed table (`log2(table.len())`)
+/// or an error if the length is not a power of two
+///
+/// ## Example
+///
+/// ```rust
+/// assert_eq!(bool_algebra::table_vars(&vec![false, true, true, false]), Ok(2));
+/// assert!(bool_algebra::table_vars(&vec![false, true, true]).is_err());
+/// ```
+pub fn table_vars(table: &[bool]) -> Result<usize, String> {
+    if table.is_empty() || !table.len().is_power_of_two() {
+        return Err(format!(
+            "unexpected table len, expected a power of 2 got {}",
+            table.len()
+        ));
+    }
+    Ok(table.len().trailing_zeros() as usize)
+}
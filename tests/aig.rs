@@ -0,0 +1,150 @@
+use bool_algebra::{parse, parse_with_order, Aig, AigLit, AigNode, Token};
+
+mod common;
+
+use common::{operators, var};
+
+fn adder() -> Aig {
+    // sum and carry out of a full adder
+    let sum = vec![var("a"), Token::Xor, var("b"), Token::Xor, var("c")];
+    let carry = vec![
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("c"),
+        Token::And,
+        Token::Open,
+        var("a"),
+        Token::Xor,
+        var("b"),
+        Token::Close,
+    ];
+    Aig::from_named_funcs(&[("sum".to_string(), sum), ("carry".to_string(), carry)]).unwrap()
+}
+
+#[test]
+fn tables() {
+    let func = operators();
+    let aig = Aig::from_funcs(std::slice::from_ref(&func)).unwrap();
+    assert_eq!(aig.tables(), Ok(vec![parse(&func).unwrap()]));
+    assert_eq!(aig.and_count(), aig.nodes().len() - 5);
+}
+
+#[test]
+fn hashing() {
+    let mut aig = Aig::new();
+    let a = aig.add_input("a").unwrap();
+    let b = aig.add_input("b").unwrap();
+
+    let and = aig.and(a, b);
+    assert_eq!(aig.and(b, a), and);
+    assert_eq!(aig.and(a, AigLit::TRUE), a);
+    assert_eq!(aig.and(AigLit::FALSE, b), AigLit::FALSE);
+    assert_eq!(aig.and(a, a), a);
+    assert_eq!(aig.and(!a, a), AigLit::FALSE);
+    assert_eq!(aig.or(a, !a), AigLit::TRUE);
+    assert_eq!(aig.and_count(), 1);
+    assert_eq!(aig.nodes()[and.node()], AigNode::And(a, b));
+
+    assert!(aig.add_input("c").is_err());
+    assert!(aig.add_output("x", AigLit::new(10, false)).is_err());
+    aig.add_output("x", !and).unwrap();
+    assert!(aig.add_output("x", and).is_err());
+    assert_eq!(aig.simulate(&[true, true]), Ok(vec![false]));
+    assert!(aig.simulate(&[true]).is_err());
+
+    // constants are folded while building
+    let func = vec![
+        var("a"),
+        Token::And,
+        Token::One,
+        Token::Or,
+        var("a"),
+        Token::And,
+        Token::Not,
+        var("a"),
+    ];
+    let aig = Aig::from_funcs(&[func]).unwrap();
+    assert_eq!(aig.and_count(), 0);
+    assert_eq!(aig.outputs()[0].1, AigLit::new(1, false));
+}
+
+#[test]
+fn ascii() {
+    let aig = adder();
+    let aag = aig.to_aag();
+    assert!(aag.starts_with(&format!(
+        "aag {} 3 0 2 {}\n2\n4\n6\n",
+        3 + aig.and_count(),
+        aig.and_count()
+    )));
+    assert!(aag.ends_with("i0 a\ni1 b\ni2 c\no0 sum\no1 carry\n"));
+    assert_eq!(Aig::from_aiger(aag.as_bytes()), Ok(aig));
+}
+
+#[test]
+fn binary() {
+    let aig = adder();
+    let data = aig.to_aig();
+    assert!(data.starts_with(
+        format!("aig {} 3 0 2 {}\n", 3 + aig.and_count(), aig.and_count()).as_bytes()
+    ));
+    assert_eq!(Aig::from_aiger(&data), Ok(aig));
+}
+
+#[test]
+fn read() {
+    // the gates don't have to be in order, comments are ignored
+    let aag = "aag 5 2 0 1 3\n2\n4\n10\n10 9 7\n6 2 4\n8 3 5\nc\na comment\n";
+    let aig = Aig::from_aiger(aag.as_bytes()).unwrap();
+    assert_eq!(aig.outputs()[0].0, "o0");
+    // !(a & b) & !(!a & !b)
+    assert_eq!(aig.tables(), Ok(vec![vec![false, true, true, false]]));
+
+    // names don't have to be ascii
+    let aig = Aig::from_aiger("aag 1 1 0 1 0\n2\n2\ni0 é\no0 ü\n".as_bytes()).unwrap();
+    assert_eq!(aig.inputs(), &["é".to_string()]);
+
+    // a constant output
+    let aig = Aig::from_aiger(b"aag 0 0 0 1 0\n1\n").unwrap();
+    assert_eq!(aig.tables(), Ok(vec![vec![true]]));
+
+    let errors = [
+        "aig 1 0 1 0 0\n2 3\n",
+        "aag 1 1 0 1 0\n2\n4\n",
+        "aag 3 2 0 1 1\n2\n4\n6\n6 8 2\n",
+        "aag 4 1 0 1 2\n2\n6\n6 8 2\n8 6 2\n",
+        "aag 2 1 0 1 1\n2\n4\n2 3 3\n",
+        "aag 1 1 0 1 0\n3\n2\n",
+        "aag 2 2 0 0 1\n2\n4\n",
+        "aag 1 1 0 0 0\n2\ni0 a\nx\n",
+        "aag 1 1 0 1 0\n2\n2\néx\n",
+        "aig 1 1 0 1 0 1\n2\n",
+        "abc 0 0 0 0 0\n",
+        "aag 1",
+        "aag 18446744073709551615 0 0 0 0\n",
+        "aag 1000000000000000 0 0 0 0\n",
+        "aag 18446744073709551615 18446744073709551615 0 0 1\n",
+        "aag 3 1 0 1 1\n2\n",
+        "aig 1000000000000000 0 0 0 1000000000000000\n",
+        "aig 2 0 0 1 1\n",
+    ];
+    for error in errors.iter() {
+        assert!(Aig::from_aiger(error.as_bytes()).is_err(), "{}", error);
+    }
+}
+
+#[test]
+fn funcs() {
+    let func = operators();
+    let aig = Aig::from_funcs(std::slice::from_ref(&func)).unwrap();
+    let back = aig.to_funcs();
+    assert_eq!(back.len(), 1);
+    assert_eq!(parse_with_order(&back[0], aig.inputs()), parse(&func));
+
+    let aig = adder();
+    for (func, table) in aig.to_funcs().iter().zip(aig.tables().unwrap()) {
+        assert_eq!(parse_with_order(func, aig.inputs()), Ok(table));
+    }
+}
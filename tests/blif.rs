@@ -0,0 +1,138 @@
+use bool_algebra::{parse_full, parse_with_order, Blif};
+
+mod common;
+
+use common::names;
+
+fn str2_bool(input: &str) -> Vec<bool> {
+    let mut result = Vec::new();
+    for c in input.chars() {
+        if c == '1' {
+            result.push(true);
+        } else if c == '0' {
+            result.push(false);
+        }
+    }
+    result
+}
+
+const FULL_ADDER: &str = "
+# a full adder
+.model full_adder
+.inputs a b \\
+    cin
+.outputs sum cout
+.names t cin sum
+10 1
+01 1
+.names a b t   # the nodes don't have to be in order
+10 1
+01 1
+.names a b cin cout
+11- 1
+1-1 1
+-11 1
+
+.end
+.model ignored
+.inputs x
+";
+
+#[test]
+fn read() {
+    let blif = Blif::from_blif(FULL_ADDER).unwrap();
+    assert_eq!(blif.model, "full_adder");
+    assert_eq!(blif.inputs, names(&["a", "b", "cin"]));
+    assert_eq!(blif.outputs, names(&["sum", "cout"]));
+    assert_eq!(blif.nodes.len(), 3);
+    assert_eq!(blif.nodes[1].inputs, names(&["a", "b"]));
+    assert_eq!(blif.nodes[1].table, str2_bool("0110"));
+
+    assert_eq!(
+        blif.tables(),
+        Ok(vec![str2_bool("01101001"), str2_bool("00010111")])
+    );
+}
+
+#[test]
+fn funcs() {
+    let blif = Blif::from_blif(FULL_ADDER).unwrap();
+    let tables = blif.tables().unwrap();
+    let funcs = blif.funcs().unwrap();
+    for (func, table) in funcs.iter().zip(tables) {
+        assert_eq!(parse_with_order(func, &blif.inputs), Ok(table));
+    }
+}
+
+#[test]
+fn write() {
+    // a b | x y
+    let tables = parse_full(
+        2,
+        2,
+        str2_bool(
+            "00 00
+            01 01
+            10 11
+            11 01",
+        ),
+    )
+    .unwrap();
+    let blif =
+        Blif::from_tables("test", &names(&["a", "b"]), &names(&["x", "y"]), &tables).unwrap();
+    let text = blif.to_blif();
+    assert_eq!(
+        text,
+        ".model test\n.inputs a b\n.outputs x y\n.names a b x\n10 1\n.names a b y\n01 1\n10 1\n11 1\n.end\n"
+    );
+
+    let read = Blif::from_blif(&text).unwrap();
+    assert_eq!(read, blif);
+    assert_eq!(read.tables(), Ok(tables));
+
+    assert!(Blif::from_tables(
+        "test",
+        &names(&["a"]),
+        &names(&["x", "y"]),
+        &[vec![true, false]]
+    )
+    .is_err());
+    assert!(Blif::from_tables("test", &names(&["a"]), &names(&["x"]), &[vec![true]]).is_err());
+}
+
+#[test]
+fn constants() {
+    let text = ".model c\n.inputs a\n.outputs zero one not\n.names zero\n.names one\n1\n.names a not\n1 0\n.end\n";
+    let blif = Blif::from_blif(text).unwrap();
+    assert_eq!(
+        blif.tables(),
+        Ok(vec![str2_bool("00"), str2_bool("11"), str2_bool("10")])
+    );
+    assert_eq!(
+        Blif::from_blif(&blif.to_blif()).unwrap().tables(),
+        blif.tables()
+    );
+}
+
+#[test]
+fn errors() {
+    let errors = [
+        // not driven
+        ".model e\n.inputs a\n.outputs y\n.end\n",
+        // driven twice
+        ".model e\n.inputs a\n.outputs y\n.names a y\n1 1\n.names a y\n0 1\n.end\n",
+        // a cycle
+        ".model e\n.inputs a\n.outputs y\n.names a t y\n11 1\n.names y t\n1 1\n.end\n",
+        // latches are not supported
+        ".model e\n.inputs a\n.outputs y\n.latch a y 0\n.end\n",
+        // cube without .names
+        ".model e\n11 1\n.end\n",
+        // invalid cube
+        ".model e\n.inputs a\n.outputs y\n.names a y\n11 1\n.end\n",
+        // missing model
+        ".inputs a\n",
+    ];
+    for error in errors.iter() {
+        assert!(Blif::from_blif(error).is_err(), "{}", error);
+    }
+}
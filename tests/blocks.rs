@@ -0,0 +1,127 @@
+use bool_algebra::{parse_full, parse_with_order, Block, Circuit};
+
+/// the functions have the same tables as the generated ones
+fn check(block: &Block) {
+    assert_eq!(block.funcs.len(), block.outputs.len());
+    assert_eq!(block.tables.len(), block.outputs.len());
+    for (func, table) in block.funcs.iter().zip(&block.tables) {
+        assert_eq!(parse_with_order(func, &block.inputs).as_ref(), Ok(table));
+    }
+    assert_eq!(
+        parse_full(block.inputs.len(), block.outputs.len(), block.full_table()),
+        Ok(block.tables.clone())
+    );
+}
+
+/// the outputs of a row as a number
+fn output(block: &Block, row: usize) -> usize {
+    block
+        .tables
+        .iter()
+        .fold(0, |value, table| value << 1 | table[row] as usize)
+}
+
+#[test]
+fn adders() {
+    for n in 1..=3 {
+        let ripple = Block::ripple_carry_adder(n).unwrap();
+        let lookahead = Block::carry_lookahead_adder(n).unwrap();
+        check(&ripple);
+        check(&lookahead);
+        assert_eq!(ripple.tables, lookahead.tables);
+        assert_eq!(ripple.inputs.len(), 2 * n + 1);
+        assert_eq!(ripple.outputs.len(), n + 1);
+    }
+
+    // a = 101, b = 011, cin = 0: 101 + 011 = 1000 = 1000
+    let adder = Block::ripple_carry_adder(3).unwrap();
+    assert_eq!(output(&adder, 0b1010110), 0b1000);
+    // a = 111, b = 111, cin = 1 = 1111
+    assert_eq!(output(&adder, 0b1111111), 0b1111);
+}
+
+#[test]
+fn lookahead_depth() {
+    // the carry of the lookahead adder doesn't go through every bit
+    let ripple = Block::ripple_carry_adder(4).unwrap();
+    let lookahead = Block::carry_lookahead_adder(4).unwrap();
+    let depth = |block: &Block| {
+        Circuit::from_funcs(&block.funcs[..1])
+            .unwrap()
+            .metrics()
+            .depth
+    };
+    assert!(depth(&lookahead) < depth(&ripple));
+}
+
+#[test]
+fn comparator() {
+    let block = Block::comparator(2).unwrap();
+    check(&block);
+    // 10 > 01
+    assert_eq!(output(&block, 0b10_01), 0b100);
+    assert_eq!(output(&block, 0b11_11), 0b010);
+    assert_eq!(output(&block, 0b01_11), 0b001);
+}
+
+#[test]
+fn selection() {
+    for n in 1..=3 {
+        check(&Block::multiplexer(n).unwrap());
+        check(&Block::decoder(n).unwrap());
+        check(&Block::encoder(n).unwrap());
+    }
+
+    let mux = Block::multiplexer(2).unwrap();
+    assert_eq!(mux.inputs, vec!["s1", "s0", "d0", "d1", "d2", "d3"]);
+    // s = 10 selects d2
+    assert_eq!(output(&mux, 0b10_0010), 1);
+    assert_eq!(output(&mux, 0b10_1101), 0);
+
+    let decoder = Block::decoder(2).unwrap();
+    assert_eq!(output(&decoder, 0b01), 0b0100);
+
+    // more outputs than bits in a usize
+    let decoder = Block::decoder(7).unwrap();
+    check(&decoder);
+    for row in [0, 5, 127].iter() {
+        let true_outputs: Vec<usize> = (0..128).filter(|&i| decoder.tables[i][*row]).collect();
+        assert_eq!(true_outputs, vec![*row]);
+    }
+    assert_eq!(Block::decoder(12).unwrap().outputs.len(), 4096);
+
+    let encoder = Block::encoder(2).unwrap();
+    assert_eq!(encoder.outputs, vec!["y1", "y0", "v"]);
+    // d1 and d2 are true, the highest is 2
+    assert_eq!(output(&encoder, 0b0110), 0b101);
+    assert_eq!(output(&encoder, 0), 0);
+}
+
+#[test]
+fn counting() {
+    for n in 1..=5 {
+        check(&Block::parity(n).unwrap());
+        check(&Block::majority(n).unwrap());
+        check(&Block::popcount(n).unwrap());
+    }
+
+    let popcount = Block::popcount(4).unwrap();
+    assert_eq!(popcount.outputs, vec!["c2", "c1", "c0"]);
+    assert_eq!(output(&popcount, 0b1111), 4);
+    assert_eq!(output(&popcount, 0b1010), 2);
+
+    let majority = Block::majority(4).unwrap();
+    assert_eq!(output(&majority, 0b1010), 0);
+    assert_eq!(output(&majority, 0b1110), 1);
+}
+
+#[test]
+fn errors() {
+    assert!(Block::ripple_carry_adder(0).is_err());
+    assert!(Block::parity(0).is_err());
+    assert!(Block::ripple_carry_adder(12).is_err());
+    assert!(Block::multiplexer(5).is_err());
+    assert!(Block::encoder(100).is_err());
+    assert!(Block::decoder(13).is_err());
+    assert!(Block::decoder(100).is_err());
+}
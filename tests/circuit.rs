@@ -0,0 +1,104 @@
+use bool_algebra::{parse, parse_with_order, Circuit, GateKind, Signal, Token};
+
+mod common;
+
+use common::{operators, var};
+
+#[test]
+fn tables() {
+    let func = operators();
+    let circuit = Circuit::from_funcs(std::slice::from_ref(&func)).unwrap();
+    assert_eq!(circuit.tables(), Ok(vec![parse(&func).unwrap()]));
+    assert_eq!(circuit.outputs()[0].0, "out0");
+}
+
+#[test]
+fn multiple_outputs() {
+    let first = vec![var("a"), Token::And, var("b")];
+    let second = vec![var("c"), Token::Or, var("a"), Token::And, var("b")];
+    let third = vec![Token::One, Token::And, Token::Zero];
+    let circuit = Circuit::from_funcs(&[first.clone(), second.clone(), third]).unwrap();
+
+    // a & b is shared
+    assert_eq!(circuit.gates().len(), 2);
+    assert_eq!(circuit.outputs()[2].1, Signal::Const(false));
+
+    let names: Vec<String> = circuit.inputs().to_vec();
+    assert_eq!(
+        circuit.tables(),
+        Ok(vec![
+            parse_with_order(&first, &names).unwrap(),
+            parse_with_order(&second, &names).unwrap(),
+            vec![false; 8],
+        ])
+    );
+}
+
+#[test]
+fn large() {
+    // more than 64 rows
+    let mut func = vec![var("x0")];
+    for i in 1..8 {
+        func.push(if i % 3 == 0 { Token::Or } else { Token::Xor });
+        func.push(var(&format!("x{}", i)));
+    }
+    let circuit = Circuit::from_funcs(std::slice::from_ref(&func)).unwrap();
+    assert_eq!(circuit.tables(), Ok(vec![parse(&func).unwrap()]));
+}
+
+#[test]
+fn build() {
+    let mut circuit = Circuit::new();
+    let a = circuit.add_input("a").unwrap();
+    let b = circuit.add_input("b").unwrap();
+    let c = circuit.add_input("c").unwrap();
+    assert!(circuit.add_input("a").is_err());
+
+    let and = circuit.add_gate(GateKind::And, &[a, b, c]).unwrap();
+    // the inputs are commutative
+    assert_eq!(circuit.add_gate(GateKind::And, &[c, b, a]), Ok(and));
+    let not = circuit.add_gate(GateKind::Not, &[and]).unwrap();
+    let buf = circuit.add_gate(GateKind::Buf, &[not]).unwrap();
+    let xnor = circuit.add_gate(GateKind::Xnor, &[and, buf]).unwrap();
+    assert_eq!(circuit.gates().len(), 4);
+
+    assert!(circuit.add_gate(GateKind::Not, &[a, b]).is_err());
+    assert!(circuit.add_gate(GateKind::Or, &[a]).is_err());
+    assert!(circuit
+        .add_gate(GateKind::Or, &[a, Signal::Gate(10)])
+        .is_err());
+    assert!(circuit
+        .add_gate(GateKind::Or, &[a, Signal::Input(3)])
+        .is_err());
+
+    circuit.add_output("and", and).unwrap();
+    circuit.add_output("xnor", xnor).unwrap();
+    circuit.add_output("one", Signal::Const(true)).unwrap();
+    assert!(circuit.add_output("and", a).is_err());
+
+    assert_eq!(
+        circuit.simulate(&[true, true, true]),
+        Ok(vec![true, false, true])
+    );
+    assert_eq!(
+        circuit.simulate(&[true, false, true]),
+        Ok(vec![false, false, true])
+    );
+    assert!(circuit.simulate(&[true]).is_err());
+}
+
+#[test]
+fn gate_kinds() {
+    for kind in GateKind::ALL.iter() {
+        let inputs: &[bool] = if kind.is_unary() {
+            &[true]
+        } else {
+            &[true, true, false]
+        };
+        let expected = !matches!(
+            kind,
+            GateKind::And | GateKind::Not | GateKind::Xor | GateKind::Nor
+        );
+        assert_eq!(kind.eval(inputs), expected, "{}", kind);
+    }
+}
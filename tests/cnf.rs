@@ -0,0 +1,135 @@
+use bool_algebra::{parse, plaisted_greenbaum, tseitin, Cnf, Token};
+
+mod common;
+
+use common::var;
+
+fn complex() -> Vec<Token> {
+    // !(a ⊼ b) → (c ⊽ a) ≡ (b ← c) ⊕ (a & b | c)
+    vec![
+        Token::Not,
+        Token::Open,
+        var("a"),
+        Token::Nand,
+        var("b"),
+        Token::Close,
+        Token::ImplicAB,
+        Token::Open,
+        var("c"),
+        Token::Nor,
+        var("a"),
+        Token::Close,
+        Token::Eq,
+        Token::Open,
+        var("b"),
+        Token::ImplicBA,
+        var("c"),
+        Token::Close,
+        Token::Xor,
+        Token::Open,
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("c"),
+        Token::Close,
+    ]
+}
+
+/// the number of assignments of the auxiliary variables that satisfy the cnf for every row
+fn extensions(cnf: &Cnf) -> Vec<usize> {
+    let inputs = cnf.names.len();
+    let aux = cnf.vars - inputs;
+    (0..(1_usize << inputs))
+        .map(|row| {
+            (0..(1_usize << aux))
+                .filter(|extension| {
+                    let assignment: Vec<bool> = (0..cnf.vars)
+                        .map(|v| {
+                            if v < inputs {
+                                row >> (inputs - 1 - v) & 1 == 1
+                            } else {
+                                extension >> (v - inputs) & 1 == 1
+                            }
+                        })
+                        .collect();
+                    cnf.is_satisfied(&assignment).unwrap()
+                })
+                .count()
+        })
+        .collect()
+}
+
+#[test]
+fn equisatisfiable() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+
+    // every satisfying row has exactly one extension
+    let cnf = tseitin(&func).unwrap();
+    let expected: Vec<usize> = table.iter().map(|&b| b as usize).collect();
+    assert_eq!(extensions(&cnf), expected);
+
+    // every satisfying row has at least one extension
+    let cnf = plaisted_greenbaum(&func).unwrap();
+    let satisfiable: Vec<bool> = extensions(&cnf).iter().map(|&n| n > 0).collect();
+    assert_eq!(satisfiable, table);
+}
+
+#[test]
+fn fewer_clauses() {
+    let func = complex();
+    let full = tseitin(&func).unwrap();
+    let polarity = plaisted_greenbaum(&func).unwrap();
+    assert_eq!(full.vars, polarity.vars);
+    assert!(polarity.clauses.len() < full.clauses.len());
+}
+
+#[test]
+fn aux() {
+    // (a & b) | !(a & b) shares the subterm
+    let func = vec![
+        Token::Open,
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Close,
+        Token::Or,
+        Token::Not,
+        Token::Open,
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Close,
+    ];
+    let cnf = tseitin(&func).unwrap();
+    assert_eq!(cnf.vars, 4);
+    assert_eq!(cnf.aux[&3], vec![var("a"), Token::And, var("b")]);
+    // a & b | !(a & b)
+    assert_eq!(cnf.aux[&4], [&func[1..4], &func[5..]].concat());
+    assert_eq!(cnf.clauses.last(), Some(&vec![4]));
+
+    // only the auxiliary variables have subterms
+    for (&v, subterm) in cnf.aux.iter() {
+        assert!(v > cnf.names.len());
+        assert!(parse(subterm).is_ok());
+    }
+}
+
+#[test]
+fn constants() {
+    let cnf = tseitin(&[var("a"), Token::Or, Token::One]).unwrap();
+    assert_eq!(cnf.clauses, Vec::<Vec<i64>>::new());
+    assert!(cnf.is_satisfied(&[false]).unwrap());
+
+    let cnf = tseitin(&[var("a"), Token::And, Token::Zero]).unwrap();
+    assert_eq!(cnf.clauses, vec![Vec::new()]);
+    assert!(!cnf.is_satisfied(&[true]).unwrap());
+
+    let cnf = tseitin(&[Token::Not, var("a")]).unwrap();
+    assert_eq!(cnf.vars, 1);
+    assert_eq!(cnf.clauses, vec![vec![-1]]);
+
+    assert!(cnf.is_satisfied(&[true, false]).is_err());
+    assert!(tseitin(&[var("a"), Token::Or]).is_err());
+}
@@ -0,0 +1,128 @@
+use bool_algebra::{
+    cofactors, parse, parse_with_order, restrict, restrict_table, shannon_expand, table_cofactors,
+    Token,
+};
+
+mod common;
+
+use common::{names, var};
+
+fn complex() -> Vec<Token> {
+    // (a → b) ⊕ !(c ⊼ a) ≡ b ⊽ 0
+    vec![
+        Token::Open,
+        var("a"),
+        Token::ImplicAB,
+        var("b"),
+        Token::Close,
+        Token::Xor,
+        Token::Not,
+        Token::Open,
+        var("c"),
+        Token::Nand,
+        var("a"),
+        Token::Close,
+        Token::Eq,
+        var("b"),
+        Token::Nor,
+        Token::Zero,
+    ]
+}
+
+#[test]
+fn restrict_matches_table() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+    let all = names(&["a", "b", "c"]);
+
+    for (i, name) in all.iter().enumerate() {
+        let mut rest = all.clone();
+        rest.remove(i);
+        for &value in [false, true].iter() {
+            let restricted = restrict(&func, name, value).unwrap();
+            assert!(!restricted.contains(&Token::Var(name.clone())));
+            assert_eq!(
+                parse_with_order(&restricted, &rest),
+                restrict_table(&table, &all, name, value)
+            );
+        }
+    }
+}
+
+#[test]
+fn restrict_folds_constants() {
+    // a & b | c with a = 0 -> c
+    let func = vec![var("a"), Token::And, var("b"), Token::Or, var("c")];
+    assert_eq!(restrict(&func, "a", false), Ok(vec![var("c")]));
+
+    // a → b with a = 0 -> 1
+    let func = vec![var("a"), Token::ImplicAB, var("b")];
+    assert_eq!(restrict(&func, "a", false), Ok(vec![Token::One]));
+
+    // !!a ⊕ 1 -> !a
+    let func = vec![Token::Not, Token::Not, var("a"), Token::Xor, Token::One];
+    assert_eq!(restrict(&func, "x", false), Ok(vec![Token::Not, var("a")]));
+}
+
+#[test]
+fn restrict_keeps_precedence() {
+    // (a | b) & (c | d) with a = 0 -> b & (c | d)
+    let func = vec![
+        Token::Open,
+        var("a"),
+        Token::Or,
+        var("b"),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        var("c"),
+        Token::Or,
+        var("d"),
+        Token::Close,
+    ];
+    assert_eq!(
+        restrict(&func, "a", false),
+        Ok(vec![
+            var("b"),
+            Token::And,
+            Token::Open,
+            var("c"),
+            Token::Or,
+            var("d"),
+            Token::Close,
+        ])
+    );
+}
+
+#[test]
+fn cofactors_and_shannon() {
+    let func = complex();
+    let (f0, f1) = cofactors(&func, "c").unwrap();
+    let table = parse(&func).unwrap();
+    let all = names(&["a", "b", "c"]);
+    let rest = names(&["a", "b"]);
+    assert_eq!(
+        Ok((
+            parse_with_order(&f0, &rest).unwrap(),
+            parse_with_order(&f1, &rest).unwrap()
+        )),
+        table_cofactors(&table, &all, "c")
+    );
+
+    for name in all.iter() {
+        let expansion = shannon_expand(&func, name).unwrap();
+        assert_eq!(parse_with_order(&expansion, &all), Ok(table.clone()));
+    }
+
+    // expanding a variable that is not in the function
+    let expansion = shannon_expand(&[var("a")], "b").unwrap();
+    assert_eq!(parse(&expansion), Ok(vec![false, false, true, true]));
+}
+
+#[test]
+fn table_errors() {
+    let table = vec![false, true, true, false];
+    assert!(restrict_table(&table, &names(&["a", "b"]), "c", true).is_err());
+    assert!(table_cofactors(&table, &names(&["a"]), "a").is_err());
+    assert!(restrict(&[Token::Or], "a", true).is_err());
+}
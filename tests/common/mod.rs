@@ -0,0 +1,46 @@
+//! helpers shared by the integration tests
+
+#![allow(dead_code)]
+
+use bool_algebra::Token;
+
+pub fn var(name: &str) -> Token {
+    Token::Var(name.to_string())
+}
+
+pub fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+pub fn operators() -> Vec<Token> {
+    // !(a ⊼ b) → (c ⊽ a) ≡ (b ← c) ⊕ !(a & b | d)
+    vec![
+        Token::Not,
+        Token::Open,
+        var("a"),
+        Token::Nand,
+        var("b"),
+        Token::Close,
+        Token::ImplicAB,
+        Token::Open,
+        var("c"),
+        Token::Nor,
+        var("a"),
+        Token::Close,
+        Token::Eq,
+        Token::Open,
+        var("b"),
+        Token::ImplicBA,
+        var("c"),
+        Token::Close,
+        Token::Xor,
+        Token::Not,
+        Token::Open,
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("d"),
+        Token::Close,
+    ]
+}
@@ -0,0 +1,109 @@
+use bool_algebra::{generate_table_2d, parse, CompiledExpr, Token};
+
+mod common;
+
+use common::var;
+
+#[test]
+fn all_operators() {
+    let operators = vec![
+        (Token::And, vec![false, false, false, true]),
+        (Token::Or, vec![false, true, true, true]),
+        (Token::Xor, vec![false, true, true, false]),
+        (Token::Eq, vec![true, false, false, true]),
+        (Token::ImplicAB, vec![true, true, false, true]),
+        (Token::ImplicBA, vec![true, false, true, true]),
+        (Token::Nand, vec![true, true, true, false]),
+        (Token::Nor, vec![true, false, false, false]),
+    ];
+
+    for (operator, table) in operators {
+        let compiled = CompiledExpr::new(&[var("a"), operator.clone(), var("b")]).unwrap();
+        for (i, row) in generate_table_2d(2).iter().enumerate() {
+            assert_eq!(compiled.eval(row), table[i], "{} at {}", operator, i);
+            assert_eq!(
+                compiled.eval_bits(i as u64),
+                table[i],
+                "{} at {}",
+                operator,
+                i
+            );
+        }
+    }
+}
+
+#[test]
+fn nested() {
+    // !(a → (b ⊼ c)) ≡ (d ← a ⊽ 0) ⊕ !(c | 1)
+    let func = vec![
+        Token::Not,
+        Token::Open,
+        var("a"),
+        Token::ImplicAB,
+        Token::Open,
+        var("b"),
+        Token::Nand,
+        var("c"),
+        Token::Close,
+        Token::Close,
+        Token::Eq,
+        Token::Open,
+        var("d"),
+        Token::ImplicBA,
+        var("a"),
+        Token::Nor,
+        Token::Zero,
+        Token::Close,
+        Token::Xor,
+        Token::Not,
+        Token::Open,
+        var("c"),
+        Token::Or,
+        Token::One,
+        Token::Close,
+    ];
+    let table = parse(&func).unwrap();
+    let compiled = CompiledExpr::new(&func).unwrap();
+
+    assert_eq!(compiled.names(), &["a", "b", "c", "d"]);
+    for (i, row) in generate_table_2d(4).iter().enumerate() {
+        assert_eq!(compiled.eval(row), table[i], "at {}", i);
+        assert_eq!(compiled.eval_bits(i as u64), table[i], "at {}", i);
+    }
+}
+
+#[test]
+fn constant() {
+    let compiled = CompiledExpr::new(&[Token::One, Token::ImplicAB, Token::Zero]).unwrap();
+    assert!(!compiled.eval(&[]));
+    assert!(!compiled.eval_bits(0));
+    assert!(CompiledExpr::new(&[Token::One, Token::One]).is_err());
+}
+
+#[test]
+fn with_order() {
+    // a & !b
+    let func = vec![var("a"), Token::And, Token::Not, var("b")];
+    let names = vec!["b".to_string(), "c".to_string(), "a".to_string()];
+    let compiled = CompiledExpr::with_order(&func, &names).unwrap();
+    assert!(compiled.eval_bits(0b001));
+    assert!(compiled.eval_bits(0b011));
+    assert!(!compiled.eval_bits(0b101));
+    assert!(CompiledExpr::with_order(&func, &names[..2]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "eval_bits needs at most 64 names")]
+fn eval_bits_too_many_names() {
+    // x0 & x1 & ... & x69
+    let mut func = Vec::new();
+    for i in 0..70 {
+        if i > 0 {
+            func.push(Token::And);
+        }
+        func.push(Token::Var(format!("x{}", i)));
+    }
+    let compiled = CompiledExpr::new(&func).unwrap();
+    assert!(!compiled.eval(&[false; 70]));
+    compiled.eval_bits(0);
+}
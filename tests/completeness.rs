@@ -0,0 +1,90 @@
+use bool_algebra::{classify, is_functionally_complete, parse, PostClass, Token};
+
+#[test]
+fn complete_sets() {
+    assert_eq!(is_functionally_complete(&[Token::Nand]), Ok(()));
+    assert_eq!(is_functionally_complete(&[Token::Nor]), Ok(()));
+    assert_eq!(is_functionally_complete(&[Token::And, Token::Not]), Ok(()));
+    assert_eq!(is_functionally_complete(&[Token::Or, Token::Not]), Ok(()));
+    assert_eq!(
+        is_functionally_complete(&[Token::ImplicAB, Token::Zero]),
+        Ok(())
+    );
+    assert_eq!(
+        is_functionally_complete(&[Token::Xor, Token::And, Token::One]),
+        Ok(())
+    );
+}
+
+#[test]
+fn incomplete_sets() {
+    assert_eq!(
+        is_functionally_complete(&[Token::Xor, Token::One]),
+        Err(vec![PostClass::Linear])
+    );
+    assert_eq!(
+        is_functionally_complete(&[Token::And, Token::Or]),
+        Err(vec![PostClass::T0, PostClass::T1, PostClass::Monotone])
+    );
+    assert_eq!(
+        is_functionally_complete(&[Token::ImplicAB]),
+        Err(vec![PostClass::T1])
+    );
+    assert_eq!(
+        is_functionally_complete(&[Token::Not]),
+        Err(vec![PostClass::SelfDual, PostClass::Linear])
+    );
+    assert_eq!(is_functionally_complete(&[]), Err(PostClass::ALL.to_vec()));
+}
+
+#[test]
+fn ignores_non_operators() {
+    assert_eq!(
+        is_functionally_complete(&[Token::Open, Token::Nand, Token::Close]),
+        Ok(())
+    );
+    assert_eq!(
+        is_functionally_complete(&[Token::Var("a".to_string()), Token::And]),
+        Err(vec![PostClass::T0, PostClass::T1, PostClass::Monotone])
+    );
+}
+
+#[test]
+fn classify_tables() {
+    assert_eq!(
+        classify(&[false]),
+        Ok(vec![PostClass::T0, PostClass::Monotone, PostClass::Linear])
+    );
+    assert_eq!(
+        classify(&[true]),
+        Ok(vec![PostClass::T1, PostClass::Monotone, PostClass::Linear])
+    );
+    assert_eq!(classify(&[false, true]), Ok(PostClass::ALL.to_vec()));
+
+    // majority of a, b, c is self-dual and monotone
+    let majority = parse(&[
+        Token::Var("a".to_string()),
+        Token::And,
+        Token::Var("b".to_string()),
+        Token::Or,
+        Token::Var("a".to_string()),
+        Token::And,
+        Token::Var("c".to_string()),
+        Token::Or,
+        Token::Var("b".to_string()),
+        Token::And,
+        Token::Var("c".to_string()),
+    ])
+    .unwrap();
+    assert_eq!(
+        classify(&majority),
+        Ok(vec![
+            PostClass::T0,
+            PostClass::T1,
+            PostClass::SelfDual,
+            PostClass::Monotone
+        ])
+    );
+
+    assert!(classify(&[true, false, true]).is_err());
+}
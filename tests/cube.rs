@@ -0,0 +1,140 @@
+use bool_algebra::{dnf, parse_with_order, Cover, Cube, Literal, Token};
+
+mod common;
+
+use common::names;
+
+fn cube(s: &str) -> Cube {
+    s.parse().unwrap()
+}
+
+fn cover(vars: usize, cubes: &[&str]) -> Cover {
+    Cover::from_cubes(vars, cubes.iter().map(|s| cube(s)).collect()).unwrap()
+}
+
+#[test]
+fn cubes() {
+    let a = cube("1-0");
+    assert_eq!(
+        a.literals(),
+        &[Literal::One, Literal::DontCare, Literal::Zero]
+    );
+    assert_eq!(a.to_string(), "1-0");
+    assert_eq!(a.literal_count(), 2);
+    assert!(a.covers(0b100) && a.covers(0b110) && !a.covers(0b101));
+    assert_eq!(Cube::minterm(0b101, 3), cube("101"));
+    assert!(Cube::universe(3).contains(&a));
+    assert!(a.contains(&cube("110")));
+    assert!(!a.contains(&cube("11-")));
+
+    assert_eq!(a.distance(&cube("011")), 2);
+    assert_eq!(a.supercube(&cube("011")), cube("--1").supercube(&a));
+    assert_eq!(a.consensus(&cube("011")), None);
+    assert_eq!(a.consensus(&cube("0-0")), Some(cube("--0")));
+
+    // the sharp is disjoint and covers the difference
+    let sharp = Cube::universe(3).sharp(&a);
+    assert_eq!(sharp.len(), 2);
+    for row in 0..8 {
+        let count = sharp.iter().filter(|c| c.covers(row)).count();
+        assert_eq!(count, usize::from(!a.covers(row)));
+    }
+    assert_eq!(a.sharp(&cube("0--")), vec![a.clone()]);
+    assert_eq!(a.sharp(&Cube::universe(3)), vec![]);
+
+    assert!("10x".parse::<Cube>().is_err());
+}
+
+#[test]
+fn funcs() {
+    let names = names(&["a", "b", "c"]);
+    let table = vec![false, true, true, true, false, false, true, true];
+    let func = dnf(&table, &names, true).unwrap();
+
+    let cover = Cover::from_func(&func, &names).unwrap();
+    assert_eq!(cover.cubes().len(), 5);
+    assert_eq!(cover.to_table(), Ok(table.clone()));
+    assert_eq!(cover.to_func(&names), Ok(func));
+    assert_eq!(Cover::from_table(&table).unwrap().to_table(), Ok(table));
+
+    // a & !a and 0 are removed, 1 is the universal cube
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::And,
+        Token::Not,
+        Token::Var("a".to_string()),
+        Token::Or,
+        Token::Var("b".to_string()),
+        Token::And,
+        Token::Zero,
+        Token::Or,
+        Token::One,
+    ];
+    let cover = Cover::from_func(&func, &names).unwrap();
+    assert_eq!(cover.cubes(), &[Cube::universe(3)]);
+    assert_eq!(cover.to_func(&names), Ok(vec![Token::One]));
+    assert_eq!(Cover::new(3).to_func(&names), Ok(vec![Token::Zero]));
+
+    let xor = vec![
+        Token::Var("a".to_string()),
+        Token::Xor,
+        Token::Var("b".to_string()),
+    ];
+    assert!(Cover::from_func(&xor, &names).is_err());
+    assert!(Cover::new(2).to_func(&names).is_err());
+    assert!(Cover::from_table(&[true, false, true]).is_err());
+}
+
+#[test]
+fn set_operations() {
+    // a | b and !a | c
+    let f = cover(3, &["1--", "-1-"]);
+    let g = cover(3, &["0--", "--1"]);
+    let f_table = f.to_table().unwrap();
+    let g_table = g.to_table().unwrap();
+
+    let and = f.intersect(&g).unwrap().to_table().unwrap();
+    let sharp = f.sharp(&g).unwrap().to_table().unwrap();
+    let complement = f.complement().to_table().unwrap();
+    for row in 0..8 {
+        assert_eq!(and[row], f_table[row] && g_table[row]);
+        assert_eq!(sharp[row], f_table[row] && !g_table[row]);
+        assert_eq!(complement[row], !f_table[row]);
+    }
+
+    assert_eq!(f.contains_cube(&cube("11-")), Ok(true));
+    assert_eq!(f.contains_cube(&cube("0--")), Ok(false));
+    assert_eq!(f.contains(&cover(3, &["1-0", "01-"])), Ok(true));
+    assert_eq!(f.contains(&g), Ok(false));
+    assert!(f.contains(&Cover::new(2)).is_err());
+    assert!(f.contains_cube(&cube("1-")).is_err());
+
+    assert!(!f.is_tautology());
+    let mut union = Cover::from_cubes(3, [f.cubes(), g.cubes()].concat()).unwrap();
+    assert!(union.is_tautology());
+    assert!(!Cover::new(3).is_tautology());
+    assert!(Cover::new(0).complement().is_tautology());
+
+    union.push(cube("11-")).unwrap();
+    assert!(union.push(cube("1")).is_err());
+    union.remove_contained();
+    assert_eq!(union.cubes().len(), 4);
+}
+
+#[test]
+fn complement_of_a_larger_function() {
+    // the majority of 5 variables
+    let names = names(&["a", "b", "c", "d", "e"]);
+    let table: Vec<bool> = (0..32_u32).map(|row| row.count_ones() >= 3).collect();
+    let f = Cover::from_table(&table).unwrap();
+    let complement = f.complement();
+    let expected: Vec<bool> = table.iter().map(|b| !b).collect();
+    assert_eq!(complement.to_table(), Ok(expected.clone()));
+    let func = complement.to_func(&names).unwrap();
+    assert_eq!(parse_with_order(&func, &names), Ok(expected));
+    assert!(
+        Cover::from_cubes(5, [f.cubes(), complement.cubes()].concat())
+            .unwrap()
+            .is_tautology()
+    );
+}
@@ -0,0 +1,129 @@
+use bool_algebra::{dnf, espresso, parse_with_order, Block, CompiledExpr, Token};
+
+fn names(len: usize) -> Vec<String> {
+    (0..len).map(|i| format!("x{}", i)).collect()
+}
+
+/// the number of product terms of a sum of products
+fn terms(func: &[Token]) -> usize {
+    func.iter().filter(|token| **token == Token::Or).count() + 1
+}
+
+/// checks the functions against the given rows
+fn check(in_len: usize, out_len: usize, table: &[bool], funcs: &[Vec<Token>], names: &[String]) {
+    let compiled: Vec<CompiledExpr> = funcs
+        .iter()
+        .map(|func| CompiledExpr::with_order(func, names).unwrap())
+        .collect();
+    for row in table.chunks(in_len + out_len) {
+        for (output, &value) in row[in_len..].iter().enumerate() {
+            assert_eq!(compiled[output].eval(&row[..in_len]), value);
+        }
+    }
+}
+
+#[test]
+fn majority() {
+    let block = Block::majority(5).unwrap();
+    let funcs = espresso(5, 1, block.full_table(), None, &block.inputs).unwrap();
+    assert_eq!(
+        parse_with_order(&funcs[0], &block.inputs),
+        Ok(block.tables[0].clone())
+    );
+    // every product of 3 of the 5 inputs
+    assert_eq!(terms(&funcs[0]), 10);
+}
+
+#[test]
+fn dont_cares() {
+    // a bcd digit is at least 5, the rows 10 to 15 are missing
+    let names = names(4);
+    let mut table = Vec::new();
+    for digit in 0..10 {
+        table.extend((0..4).rev().map(|i| digit >> i & 1 == 1));
+        table.push(digit >= 5);
+    }
+
+    let funcs = espresso(4, 1, table.clone(), None, &names).unwrap();
+    check(4, 1, &table, &funcs, &names);
+    // x0 | x1 & x3 | x1 & x2
+    assert_eq!(terms(&funcs[0]), 3);
+
+    // without the don't-cares it needs more literals
+    let filled = espresso(4, 1, table.clone(), Some(false), &names).unwrap();
+    check(4, 1, &table, &filled, &names);
+    assert_eq!(
+        parse_with_order(&filled[0], &names).unwrap()[10..],
+        [false; 6]
+    );
+    let literals = |func: &[Token]| func.iter().filter(|t| matches!(t, Token::Var(_))).count();
+    assert!(literals(&filled[0]) > literals(&funcs[0]));
+
+    let ones = espresso(4, 1, table.clone(), Some(true), &names).unwrap();
+    check(4, 1, &table, &ones, &names);
+    assert_eq!(parse_with_order(&ones[0], &names).unwrap()[10..], [true; 6]);
+}
+
+#[test]
+fn shared_terms() {
+    let block = Block::ripple_carry_adder(2).unwrap();
+    let (inputs, outputs) = (block.inputs.len(), block.outputs.len());
+    let funcs = espresso(inputs, outputs, block.full_table(), None, &block.inputs).unwrap();
+    for (func, table) in funcs.iter().zip(&block.tables) {
+        assert_eq!(parse_with_order(func, &block.inputs).as_ref(), Ok(table));
+        // never worse than the canonical form
+        let canonical = dnf(table, &block.inputs, true).unwrap();
+        assert!(terms(func) <= terms(&canonical));
+    }
+}
+
+#[test]
+fn many_inputs() {
+    // 24 inputs with a few hundred rows, the full table would have 16 million rows
+    let names = names(24);
+    let mut state: u64 = 1;
+    let mut table = Vec::new();
+    for _ in 0..300 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let row = (state >> 40) as usize;
+        table.extend((0..24).rev().map(|i| row >> i & 1 == 1));
+        // x0 | x1 & !x2 and the parity of the three
+        let (x0, x1, x2) = (row >> 23 & 1 == 1, row >> 22 & 1 == 1, row >> 21 & 1 == 1);
+        table.push(x0 || x1 && !x2);
+        table.push(x0 ^ x1 ^ x2);
+    }
+
+    let funcs = espresso(24, 2, table.clone(), None, &names).unwrap();
+    check(24, 2, &table, &funcs, &names);
+    assert_eq!(terms(&funcs[0]), 2);
+    assert_eq!(terms(&funcs[1]), 4);
+}
+
+#[test]
+fn constants() {
+    let names = names(1);
+    let table = vec![false, true, true, true];
+    assert_eq!(
+        espresso(1, 1, table, None, &names),
+        Ok(vec![vec![Token::One]])
+    );
+    let table = vec![false, false, true, false];
+    assert_eq!(
+        espresso(1, 1, table, None, &names),
+        Ok(vec![vec![Token::Zero]])
+    );
+    assert_eq!(
+        espresso(1, 1, vec![], Some(true), &names),
+        Ok(vec![vec![Token::One]])
+    );
+}
+
+#[test]
+fn errors() {
+    let names = names(2);
+    assert!(espresso(2, 1, vec![true, false], None, &names).is_err());
+    assert!(espresso(3, 1, vec![false; 4], None, &names).is_err());
+    assert!(espresso(2, 65, vec![false; 67], None, &names).is_err());
+}
@@ -0,0 +1,79 @@
+use bool_algebra::{eval, generate_table_2d, get_names, parse, Evaluator, Token};
+use std::collections::HashMap;
+
+fn complex() -> Vec<Token> {
+    // !((a|b)&(c|!d)) ≡ a ⊕ 1
+    vec![
+        Token::Not,
+        Token::Open,
+        Token::Open,
+        Token::Var("a".to_string()),
+        Token::Or,
+        Token::Var("b".to_string()),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        Token::Var("c".to_string()),
+        Token::Or,
+        Token::Not,
+        Token::Var("d".to_string()),
+        Token::Close,
+        Token::Close,
+        Token::Eq,
+        Token::Var("a".to_string()),
+        Token::Xor,
+        Token::One,
+    ]
+}
+
+#[test]
+fn eval_matches_parse() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+    let names = get_names(&func);
+
+    for (i, row) in generate_table_2d(names.len()).iter().enumerate() {
+        let values: HashMap<String, bool> = names.iter().cloned().zip(row.clone()).collect();
+        assert_eq!(eval(&func, &values), Ok(table[i]), "at {}", i);
+    }
+}
+
+#[test]
+fn eval_unbound() {
+    let mut values = HashMap::new();
+    values.insert("a".to_string(), true);
+    values.insert("b".to_string(), false);
+    values.insert("unused".to_string(), false);
+    assert_eq!(
+        eval(&complex(), &values),
+        Err("variable c is not bound".to_string())
+    );
+
+    values.insert("c".to_string(), false);
+    values.insert("d".to_string(), false);
+    assert!(eval(&complex(), &values).is_ok());
+}
+
+#[test]
+fn eval_const() {
+    let values = HashMap::new();
+    assert_eq!(
+        eval(&[Token::One, Token::Nand, Token::Zero], &values),
+        Ok(true)
+    );
+    assert!(eval(&[Token::One, Token::Nand], &values).is_err());
+}
+
+#[test]
+fn evaluator() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+    let evaluator = Evaluator::new(&func).unwrap();
+
+    assert_eq!(evaluator.names(), &get_names(&func)[..]);
+    for (i, row) in generate_table_2d(4).iter().enumerate() {
+        assert_eq!(evaluator.eval(row), Ok(table[i]), "at {}", i);
+    }
+    assert!(evaluator.eval(&[true, true, true]).is_err());
+    assert!(Evaluator::new(&[Token::Open]).is_err());
+}
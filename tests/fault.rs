@@ -0,0 +1,102 @@
+use bool_algebra::{Circuit, Fault, FaultSite, GateKind, Signal, Token};
+
+mod common;
+
+use common::var;
+
+#[test]
+fn full_adder() {
+    // a ^ b ^ c and a & b | c & (a ^ b)
+    let sum = vec![var("a"), Token::Xor, var("b"), Token::Xor, var("c")];
+    let carry = vec![
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("c"),
+        Token::And,
+        Token::Open,
+        var("a"),
+        Token::Xor,
+        var("b"),
+        Token::Close,
+    ];
+    let circuit = Circuit::from_funcs(&[sum, carry]).unwrap();
+    let faults = circuit.faults();
+    // 6 gates with 2 inputs: 3 wires each with 2 faults
+    assert_eq!(faults.len(), 36);
+
+    let test_set = circuit.test_set().unwrap();
+    assert!(test_set.undetectable.is_empty());
+    assert_eq!(test_set.coverage, 100.0);
+    assert!(test_set.rows.len() < 8);
+    assert_eq!(circuit.fault_coverage(&test_set.rows), Ok(100.0));
+
+    // every row is needed
+    for i in 0..test_set.rows.len() {
+        let mut rows = test_set.rows.clone();
+        rows.remove(i);
+        assert!(circuit.fault_coverage(&rows).unwrap() < 100.0);
+    }
+
+    // every fault is detected by a row of the test set
+    for fault in &faults {
+        let rows = circuit.detecting_rows(fault).unwrap();
+        assert!(rows.iter().any(|row| test_set.rows.contains(row)));
+    }
+}
+
+#[test]
+fn redundant() {
+    // a | a & b: the and gate can't be tested
+    let mut circuit = Circuit::new();
+    let a = circuit.add_input("a").unwrap();
+    let b = circuit.add_input("b").unwrap();
+    let and = circuit.add_gate(GateKind::And, &[a, b]).unwrap();
+    let or = circuit.add_gate(GateKind::Or, &[a, and]).unwrap();
+    circuit.add_output("x", or).unwrap();
+
+    let test_set = circuit.test_set().unwrap();
+    let undetectable = Fault {
+        site: FaultSite::Output(0),
+        stuck_at: false,
+    };
+    assert!(test_set.undetectable.contains(&undetectable));
+    assert_eq!(circuit.detecting_rows(&undetectable), Ok(vec![]));
+    assert!(test_set.coverage < 100.0);
+    assert_eq!(
+        circuit.fault_coverage(&test_set.rows),
+        Ok(test_set.coverage)
+    );
+}
+
+#[test]
+fn simulate_fault() {
+    let mut circuit = Circuit::new();
+    let a = circuit.add_input("a").unwrap();
+    let not = circuit.add_gate(GateKind::Not, &[a]).unwrap();
+    circuit.add_output("x", not).unwrap();
+    circuit.add_output("one", Signal::Const(true)).unwrap();
+
+    let fault = Fault {
+        site: FaultSite::Input(0, 0),
+        stuck_at: true,
+    };
+    assert_eq!(circuit.simulate_fault(&fault, &[0b01]), Ok(vec![0, !0]));
+    assert_eq!(circuit.detecting_rows(&fault), Ok(vec![0]));
+    assert_eq!(circuit.fault_coverage(&[]), Ok(0.0));
+    assert_eq!(circuit.fault_coverage(&[0, 1]), Ok(100.0));
+
+    assert!(circuit.fault_coverage(&[2]).is_err());
+    assert!(circuit.simulate_fault(&fault, &[]).is_err());
+    let missing = Fault {
+        site: FaultSite::Input(0, 1),
+        stuck_at: false,
+    };
+    assert!(circuit.simulate_fault(&missing, &[0]).is_err());
+    let missing = Fault {
+        site: FaultSite::Output(1),
+        stuck_at: false,
+    };
+    assert!(circuit.detecting_rows(&missing).is_err());
+}
@@ -0,0 +1,110 @@
+use bool_algebra::{
+    dnf, hazard_free_cover, parse_with_order, static_hazards, Hazard, HazardKind, Token,
+};
+
+mod common;
+
+use common::{names, var};
+
+#[test]
+fn canonical_dnf() {
+    // every pair of adjacent true rows is a hazard in the canonical form
+    let table = vec![false, true, true, true, false, false, true, true];
+    let names = names(&["a", "b", "c"]);
+    let sop = dnf(&table, &names, true).unwrap();
+
+    let hazards = static_hazards(&sop, &table, &names).unwrap();
+    assert_eq!(hazards.len(), 5);
+    assert!(hazards
+        .iter()
+        .all(|hazard| hazard.kind == HazardKind::Static1));
+    assert!(hazards.contains(&Hazard {
+        kind: HazardKind::Static1,
+        rows: (0b010, 0b110),
+        var: "a".to_string(),
+    }));
+
+    let cover = hazard_free_cover(&sop, &table, &names).unwrap();
+    assert_eq!(static_hazards(&cover, &table, &names), Ok(vec![]));
+    assert_eq!(parse_with_order(&cover, &names), Ok(table));
+}
+
+#[test]
+fn static_0() {
+    // a & !a & b | c
+    let sop = vec![
+        var("a"),
+        Token::And,
+        Token::Not,
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("c"),
+    ];
+    let names = names(&["a", "b", "c"]);
+    let table = parse_with_order(&sop, &names).unwrap();
+
+    assert_eq!(
+        static_hazards(&sop, &table, &names),
+        Ok(vec![Hazard {
+            kind: HazardKind::Static0,
+            rows: (0b010, 0b110),
+            var: "a".to_string(),
+        }])
+    );
+
+    // the term is removed
+    let cover = hazard_free_cover(&sop, &table, &names).unwrap();
+    assert_eq!(cover, vec![Token::Open, var("c"), Token::Close]);
+}
+
+#[test]
+fn hazard_free() {
+    // a & b | !b & c | a & c is already free of hazards
+    let sop = vec![
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        Token::Not,
+        var("b"),
+        Token::And,
+        var("c"),
+        Token::Or,
+        var("a"),
+        Token::And,
+        var("c"),
+    ];
+    let names = names(&["a", "b", "c"]);
+    let table = parse_with_order(&sop, &names).unwrap();
+    assert_eq!(static_hazards(&sop, &table, &names), Ok(vec![]));
+}
+
+#[test]
+fn constants() {
+    let names = names(&["a"]);
+    assert_eq!(
+        hazard_free_cover(&[Token::One], &[true, true], &names),
+        Ok(vec![Token::One])
+    );
+    assert_eq!(
+        hazard_free_cover(&[Token::Zero], &[false, false], &names),
+        Ok(vec![Token::Zero])
+    );
+}
+
+#[test]
+fn errors() {
+    let names = names(&["a", "b"]);
+    let table = vec![false, false, false, true];
+    // not a sum of products
+    let sop = vec![var("a"), Token::Xor, var("b")];
+    assert!(static_hazards(&sop, &[false, true, true, false], &names).is_err());
+    // the table doesn't match
+    let sop = vec![var("a"), Token::Or, var("b")];
+    assert!(static_hazards(&sop, &table, &names).is_err());
+    // a variable that is not in names
+    let sop = vec![var("a"), Token::And, var("c")];
+    assert!(hazard_free_cover(&sop, &table, &names).is_err());
+}
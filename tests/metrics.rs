@@ -0,0 +1,101 @@
+use bool_algebra::{dnf, metrics, Circuit, GateKind, Signal, Token};
+
+mod common;
+
+use common::{names, var};
+
+#[test]
+fn dnf_vs_minimized() {
+    // majority of a, b, c
+    let table = vec![false, false, false, true, false, true, true, true];
+    let full = dnf(&table, &names(&["a", "b", "c"]), true).unwrap();
+    // a & b | a & c | b & c
+    let minimized = vec![
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("a"),
+        Token::And,
+        var("c"),
+        Token::Or,
+        var("b"),
+        Token::And,
+        var("c"),
+    ];
+
+    let full = metrics(&full).unwrap();
+    let minimized = metrics(&minimized).unwrap();
+
+    assert_eq!(full.literals, 12);
+    assert_eq!(full.gates[&Token::Not], 3);
+    assert_eq!(full.gates[&Token::Or], 3);
+    assert_eq!(full.gates[&Token::And], 8);
+
+    assert_eq!(minimized.literals, 6);
+    assert_eq!(minimized.gates.get(&Token::Not), None);
+    assert_eq!(minimized.gate_count(), 5);
+    assert_eq!(minimized.max_fan_in, 2);
+    assert_eq!(minimized.max_fan_out, 2);
+
+    // the or chain is read from the right: a & b | (a & c | b & c)
+    assert_eq!(minimized.depth, 3);
+    assert_eq!(minimized.critical_input, Some("a".to_string()));
+    assert_eq!(
+        minimized.critical_path,
+        vec![Token::And, Token::Or, Token::Or]
+    );
+    assert!(full.depth > minimized.depth);
+}
+
+#[test]
+fn trivial() {
+    let single = metrics(&[var("a")]).unwrap();
+    assert_eq!(single.gate_count(), 0);
+    assert_eq!(single.depth, 0);
+    assert_eq!(single.max_fan_out, 1);
+    assert_eq!(single.critical_input, Some("a".to_string()));
+    assert!(single.critical_path.is_empty());
+
+    let constant = metrics(&[Token::Not, Token::One]).unwrap();
+    assert_eq!(constant.gate_count(), 1);
+    assert_eq!(constant.literals, 0);
+    assert_eq!(constant.critical_input, None);
+    assert_eq!(constant.critical_path, vec![Token::Not]);
+
+    assert!(metrics(&[var("a"), Token::And]).is_err());
+}
+
+#[test]
+fn netlist() {
+    let mut circuit = Circuit::new();
+    let a = circuit.add_input("a").unwrap();
+    let b = circuit.add_input("b").unwrap();
+    let c = circuit.add_input("c").unwrap();
+    let and = circuit.add_gate(GateKind::And, &[a, b, c]).unwrap();
+    let not = circuit.add_gate(GateKind::Not, &[and]).unwrap();
+    let nor = circuit.add_gate(GateKind::Nor, &[not, a]).unwrap();
+    let xor = circuit.add_gate(GateKind::Xor, &[and, c]).unwrap();
+    circuit.add_output("x", nor).unwrap();
+    circuit.add_output("y", xor).unwrap();
+    circuit.add_output("z", and).unwrap();
+    circuit.add_output("one", Signal::Const(true)).unwrap();
+
+    let metrics = circuit.metrics();
+    assert_eq!(metrics.gate_count(), 4);
+    assert_eq!(metrics.gates[&GateKind::Nor], 1);
+    assert_eq!(metrics.literals, 5);
+    assert_eq!(metrics.max_fan_in, 3);
+    // the and gate drives the not, the xor and z
+    assert_eq!(metrics.max_fan_out, 3);
+    assert_eq!(metrics.depth, 3);
+    assert_eq!(metrics.critical_input, Some("a".to_string()));
+    assert_eq!(
+        metrics.critical_path,
+        vec![GateKind::And, GateKind::Not, GateKind::Nor]
+    );
+
+    let empty = Circuit::new().metrics();
+    assert_eq!(empty.gate_count(), 0);
+    assert_eq!(empty.critical_input, None);
+}
@@ -0,0 +1,146 @@
+use bool_algebra::{count_models, get_names, model_cubes, models, parse, Token};
+use std::collections::HashMap;
+
+mod common;
+
+use common::var;
+
+fn complex() -> Vec<Token> {
+    // (a ⊕ b) & (c → d) | !a & c ↔ b
+    vec![
+        Token::Open,
+        var("a"),
+        Token::Xor,
+        var("b"),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        var("c"),
+        Token::ImplicAB,
+        var("d"),
+        Token::Close,
+        Token::Or,
+        Token::Not,
+        var("a"),
+        Token::And,
+        var("c"),
+        Token::Eq,
+        var("b"),
+    ]
+}
+
+fn pairs(len: usize) -> Vec<Token> {
+    // (x0 | y0) & (x1 ⊕ y1) & (x2 | y2) & ...
+    let mut func = Vec::new();
+    for i in 0..len {
+        if i != 0 {
+            func.push(Token::And);
+        }
+        func.push(Token::Open);
+        func.push(var(&format!("x{}", i)));
+        func.push(if i % 2 == 0 { Token::Or } else { Token::Xor });
+        func.push(var(&format!("y{}", i)));
+        func.push(Token::Close);
+    }
+    func
+}
+
+fn ones(table: &[bool]) -> u128 {
+    table.iter().filter(|&&b| b).count() as u128
+}
+
+#[test]
+fn count() {
+    let func = complex();
+    assert_eq!(count_models(&func), Ok(ones(&parse(&func).unwrap())));
+
+    assert_eq!(count_models(&[Token::One]), Ok(1));
+    assert_eq!(count_models(&[Token::Zero]), Ok(0));
+    assert_eq!(
+        count_models(&[var("a"), Token::And, Token::Not, var("a")]),
+        Ok(0)
+    );
+    assert_eq!(count_models(&[var("a"), Token::Or, var("b")]), Ok(3));
+
+    // constants remove variables from the expression but not from the count
+    assert_eq!(
+        count_models(&[var("a"), Token::And, Token::Zero, Token::Or, var("b")]),
+        Ok(2)
+    );
+
+    let func = pairs(6);
+    assert_eq!(count_models(&func), Ok(ones(&parse(&func).unwrap())));
+
+    assert!(count_models(&[var("a"), Token::And]).is_err());
+}
+
+#[test]
+fn count_many() {
+    // 48 variables, 3 models for every `|` pair and 2 for every `⊕` pair
+    assert_eq!(
+        count_models(&pairs(24)),
+        Ok(3_u128.pow(12) * 2_u128.pow(12))
+    );
+}
+
+#[test]
+fn cubes() {
+    let func = complex();
+    let names = get_names(&func);
+    let table = parse(&func).unwrap();
+
+    // the cubes are disjoint and cover exactly the true rows
+    let mut covered = vec![false; table.len()];
+    for cube in model_cubes(&func).unwrap() {
+        for (row, value) in covered.iter_mut().enumerate() {
+            let matches = names.iter().enumerate().all(|(i, name)| {
+                let bit = row >> (names.len() - 1 - i) & 1 == 1;
+                cube.get(name).copied().unwrap_or(bit) == bit
+            });
+            if matches {
+                assert!(!*value);
+                *value = true;
+            }
+        }
+    }
+    assert_eq!(covered, table);
+
+    assert_eq!(model_cubes(&[Token::Zero]).unwrap().count(), 0);
+    assert_eq!(
+        model_cubes(&[Token::One]).unwrap().collect::<Vec<_>>(),
+        vec![HashMap::new()]
+    );
+}
+
+#[test]
+fn assignments() {
+    let func = complex();
+    let names = get_names(&func);
+    let table = parse(&func).unwrap();
+
+    let mut rows: Vec<usize> = models(&func)
+        .unwrap()
+        .map(|model| {
+            assert_eq!(model.len(), names.len());
+            names
+                .iter()
+                .fold(0, |row, name| row << 1 | model[name] as usize)
+        })
+        .collect();
+    rows.sort_unstable();
+
+    let expected: Vec<usize> = (0..table.len()).filter(|&row| table[row]).collect();
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn lazy() {
+    // only the first few models of a huge function
+    let func = pairs(24);
+    let first: Vec<_> = models(&func).unwrap().take(5).collect();
+    assert_eq!(first.len(), 5);
+    for model in first {
+        assert_eq!(model.len(), 48);
+    }
+    assert!(model_cubes(&func).unwrap().next().is_some());
+}
@@ -0,0 +1,85 @@
+use bool_algebra::{npn_canonical, npn_equivalent, parse, NpnTransform, Token};
+use std::collections::HashSet;
+
+mod common;
+
+use common::var;
+
+fn all_tables(len: usize) -> Vec<Vec<bool>> {
+    let rows = 1 << len;
+    (0..(1_u64 << rows))
+        .map(|bits| (0..rows).map(|i| bits >> i & 1 == 1).collect())
+        .collect()
+}
+
+#[test]
+fn number_of_classes() {
+    // https://oeis.org/A000370
+    for &(len, classes) in [(0, 1), (1, 2), (2, 4), (3, 14)].iter() {
+        let canonical: HashSet<Vec<bool>> = all_tables(len)
+            .iter()
+            .map(|table| npn_canonical(table).unwrap().0)
+            .collect();
+        assert_eq!(canonical.len(), classes, "with {} variables", len);
+    }
+}
+
+#[test]
+fn transform_reproduces_canonical() {
+    for table in all_tables(3) {
+        let (canonical, transform) = npn_canonical(&table).unwrap();
+        assert_eq!(transform.apply(&table), Ok(canonical));
+    }
+}
+
+#[test]
+fn equivalent() {
+    // a & (b | c) and !(!c | !a & !b)
+    let a = parse(&[
+        var("a"),
+        Token::And,
+        Token::Open,
+        var("b"),
+        Token::Or,
+        var("c"),
+        Token::Close,
+    ])
+    .unwrap();
+    let b = parse(&[
+        Token::Not,
+        Token::Open,
+        Token::Not,
+        var("c"),
+        Token::Or,
+        Token::Not,
+        var("a"),
+        Token::And,
+        Token::Not,
+        var("b"),
+        Token::Close,
+    ])
+    .unwrap();
+    assert_eq!(npn_equivalent(&a, &b), Ok(true));
+
+    // majority is self-dual and not equivalent
+    let majority = vec![false, false, false, true, false, true, true, true];
+    assert_eq!(npn_equivalent(&a, &majority), Ok(false));
+    assert_eq!(npn_equivalent(&a, &[false, true]), Ok(false));
+}
+
+#[test]
+fn invalid_transform() {
+    let transform = NpnTransform {
+        permutation: vec![0, 0],
+        input_negation: vec![false, false],
+        output_negation: false,
+    };
+    assert!(transform.apply(&[false, true, true, true]).is_err());
+    assert!(NpnTransform::identity(1)
+        .apply(&[false, true, true, true])
+        .is_err());
+    assert_eq!(
+        NpnTransform::identity(2).apply(&[false, true, true, true]),
+        Ok(vec![false, true, true, true])
+    );
+}
@@ -0,0 +1,97 @@
+use bool_algebra::{
+    get_names_ordered, natural_cmp, parse, parse_with_order, permute_table, NameOrder, Token,
+};
+use std::cmp::Ordering;
+
+#[test]
+fn natural_names() {
+    let func = vec![
+        Token::Var("x10".to_string()),
+        Token::Or,
+        Token::Var("x2".to_string()),
+        Token::Or,
+        Token::Var("y".to_string()),
+        Token::Or,
+        Token::Var("x1".to_string()),
+        Token::Or,
+        Token::Var("x02".to_string()),
+    ];
+    assert_eq!(
+        get_names_ordered(&func, NameOrder::Natural),
+        vec!["x1", "x02", "x2", "x10", "y"]
+    );
+    assert_eq!(
+        get_names_ordered(&func, NameOrder::Alphabetical),
+        vec!["x02", "x1", "x10", "x2", "y"]
+    );
+}
+
+#[test]
+fn natural_cmp_is_consistent_with_eq() {
+    assert_eq!(natural_cmp("x01", "x1"), Ordering::Less);
+    assert_eq!(natural_cmp("x1", "x01"), Ordering::Greater);
+    assert_eq!(natural_cmp("x001", "x01"), Ordering::Less);
+    assert_eq!(natural_cmp("x01", "x01"), Ordering::Equal);
+    // the number still decides first
+    assert_eq!(natural_cmp("x02", "x10"), Ordering::Less);
+}
+
+#[test]
+fn with_order() {
+    // a ⊕ (b & c)
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::Xor,
+        Token::Open,
+        Token::Var("b".to_string()),
+        Token::And,
+        Token::Var("c".to_string()),
+        Token::Close,
+    ];
+    let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    assert_eq!(parse_with_order(&func, &names), parse(&func));
+
+    let names = vec!["c".to_string(), "a".to_string(), "b".to_string()];
+    assert_eq!(
+        parse_with_order(&func, &names),
+        Ok(vec![false, false, true, true, false, true, true, false])
+    );
+}
+
+#[test]
+fn with_order_errors() {
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::And,
+        Token::Var("b".to_string()),
+    ];
+    assert!(parse_with_order(&func, &["a".to_string()]).is_err());
+    assert!(parse_with_order(&func, &["a".to_string(), "b".to_string(), "a".to_string()]).is_err());
+}
+
+#[test]
+fn permute() {
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::ImplicAB,
+        Token::Open,
+        Token::Var("b".to_string()),
+        Token::Or,
+        Token::Not,
+        Token::Var("c".to_string()),
+        Token::Close,
+    ];
+    let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let table = parse(&func).unwrap();
+
+    let order = vec!["c".to_string(), "a".to_string(), "b".to_string()];
+    assert_eq!(
+        permute_table(&table, &names, &order),
+        parse_with_order(&func, &order)
+    );
+    assert_eq!(permute_table(&table, &names, &names), Ok(table.clone()));
+
+    assert!(permute_table(&table, &names, &order[..2]).is_err());
+    let order = vec!["a".to_string(), "b".to_string(), "d".to_string()];
+    assert!(permute_table(&table, &names, &order).is_err());
+}
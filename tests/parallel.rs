@@ -0,0 +1,33 @@
+use bool_algebra::{parse, parse_parallel, Token};
+
+#[test]
+fn parallel() {
+    // !((a|b)&(c|!d)) ⊕ e
+    let func = vec![
+        Token::Not,
+        Token::Open,
+        Token::Open,
+        Token::Var("a".to_string()),
+        Token::Or,
+        Token::Var("b".to_string()),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        Token::Var("c".to_string()),
+        Token::Or,
+        Token::Not,
+        Token::Var("d".to_string()),
+        Token::Close,
+        Token::Close,
+        Token::Xor,
+        Token::Var("e".to_string()),
+    ];
+    let table = parse(&func);
+    for threads in [1, 2, 3, 7, 32, 100, 10_000].iter() {
+        assert_eq!(parse_parallel(&func, *threads), table);
+    }
+
+    assert_eq!(parse_parallel(&[Token::Zero], 4), Ok(vec![false]));
+    assert!(parse_parallel(&func, 0).is_err());
+    assert!(parse_parallel(&[Token::And], 2).is_err());
+}
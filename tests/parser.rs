@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison, clippy::useless_vec)]
+
 use bool_algebra::parse;
 use bool_algebra::Token;
 
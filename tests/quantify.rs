@@ -0,0 +1,89 @@
+use bool_algebra::{exists, exists_table, forall, forall_table, parse, parse_with_order, Token};
+
+mod common;
+
+use common::{names, var};
+
+fn complex() -> Vec<Token> {
+    // (a ⊕ b) & (c → d) | !a & c
+    vec![
+        Token::Open,
+        var("a"),
+        Token::Xor,
+        var("b"),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        var("c"),
+        Token::ImplicAB,
+        var("d"),
+        Token::Close,
+        Token::Or,
+        Token::Not,
+        var("a"),
+        Token::And,
+        var("c"),
+    ]
+}
+
+#[test]
+fn expression_matches_table() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+    let all = names(&["a", "b", "c", "d"]);
+
+    let cases: Vec<Vec<&str>> = vec![
+        vec![],
+        vec!["a"],
+        vec!["b"],
+        vec!["d", "b"],
+        vec!["a", "c"],
+        vec!["a", "b", "c", "d"],
+        vec!["e"],
+        vec!["b", "e", "b"],
+    ];
+    for vars in cases {
+        let (exists_result, rest) = exists_table(&table, &all, &vars).unwrap();
+        let expr = exists(&func, &vars).unwrap();
+        assert_eq!(
+            parse_with_order(&expr, &rest),
+            Ok(exists_result),
+            "∃{:?}",
+            vars
+        );
+
+        let (forall_result, rest) = forall_table(&table, &all, &vars).unwrap();
+        let expr = forall(&func, &vars).unwrap();
+        assert_eq!(
+            parse_with_order(&expr, &rest),
+            Ok(forall_result),
+            "∀{:?}",
+            vars
+        );
+    }
+}
+
+#[test]
+fn remaining_names() {
+    let table = parse(&complex()).unwrap();
+    let all = names(&["a", "b", "c", "d"]);
+    assert_eq!(
+        exists_table(&table, &all, &["c", "a"]).unwrap().1,
+        names(&["b", "d"])
+    );
+    assert_eq!(
+        forall_table(&table, &all, &["e"]),
+        Ok((table.clone(), all.clone()))
+    );
+    assert_eq!(
+        exists_table(&table, &all, &["d", "d"]).unwrap().1,
+        names(&["a", "b", "c"])
+    );
+}
+
+#[test]
+fn unknown_variable() {
+    let func = vec![var("a"), Token::And, var("b")];
+    assert_eq!(exists(&func, &["x"]), Ok(func.clone()));
+    assert_eq!(forall(&func, &["x", "b"]), Ok(vec![Token::Zero]));
+}
@@ -0,0 +1,108 @@
+use bool_algebra::{generate_table_2d, parse, rows, Token};
+
+mod common;
+
+use common::var;
+
+fn complex() -> Vec<Token> {
+    // !((a|b)&(c|!d))
+    vec![
+        Token::Not,
+        Token::Open,
+        Token::Open,
+        var("a"),
+        Token::Or,
+        var("b"),
+        Token::Close,
+        Token::And,
+        Token::Open,
+        var("c"),
+        Token::Or,
+        Token::Not,
+        var("d"),
+        Token::Close,
+        Token::Close,
+    ]
+}
+
+#[test]
+fn matches_parse() {
+    let func = complex();
+    let table = parse(&func).unwrap();
+    let expected: Vec<(Vec<bool>, bool)> = generate_table_2d(4).into_iter().zip(table).collect();
+
+    assert_eq!(rows(&func).unwrap().collect::<Vec<_>>(), expected);
+    assert_eq!(rows(&func).unwrap().len(), 16);
+    assert_eq!(
+        rows(&func).unwrap().rev().collect::<Vec<_>>(),
+        expected.iter().rev().cloned().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        rows(&func).unwrap().skip(5).step_by(3).collect::<Vec<_>>(),
+        expected
+            .iter()
+            .skip(5)
+            .step_by(3)
+            .cloned()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn nth() {
+    let func = complex();
+    let mut rows = rows(&func).unwrap();
+    assert_eq!(rows.nth(5), Some((vec![false, true, false, true], true)));
+    assert_eq!(
+        rows.nth_back(2),
+        Some((vec![true, true, false, true], true))
+    );
+    assert_eq!(rows.len(), 7);
+    assert_eq!(rows.nth(7), None);
+    assert_eq!(rows.next(), None);
+}
+
+#[test]
+fn large() {
+    // x0 & x1 & ... & x39
+    let mut func = Vec::new();
+    for i in 0..40 {
+        if i != 0 {
+            func.push(Token::And);
+        }
+        func.push(var(&format!("x{:02}", i)));
+    }
+
+    let mut rows = rows(&func).unwrap();
+    assert_eq!(rows.len(), 1 << 40);
+    assert_eq!(rows.nth((1 << 40) - 1), Some((vec![true; 40], true)));
+    assert_eq!(rows.next(), None);
+
+    let mut rows = bool_algebra::rows(&func).unwrap().skip((1 << 40) - 2);
+    assert_eq!(rows.next().map(|row| row.1), Some(false));
+    assert_eq!(rows.next().map(|row| row.1), Some(true));
+}
+
+#[test]
+fn constant() {
+    let rows: Vec<_> = rows(&[Token::One]).unwrap().collect();
+    assert_eq!(rows, vec![(vec![], true)]);
+    assert!(bool_algebra::rows(&[Token::Open]).is_err());
+}
+
+#[test]
+fn too_many_rows() {
+    // x0 | x1 | ... | x63 has more rows than a usize can count
+    let mut func = Vec::new();
+    for i in 0..64 {
+        if i != 0 {
+            func.push(Token::Or);
+        }
+        func.push(Token::Var(format!("x{:02}", i)));
+    }
+    assert!(rows(&func).is_err());
+
+    let rows = rows(&func[..func.len() - 2]).unwrap();
+    assert_eq!(rows.len(), 1 << 63);
+    assert_eq!(rows.size_hint(), (1 << 63, Some(1 << 63)));
+}
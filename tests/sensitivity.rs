@@ -0,0 +1,91 @@
+use bool_algebra::{
+    block_sensitivity, boolean_difference, boolean_difference_table, influence, parse,
+    parse_with_order, sensitivity, total_influence, Token,
+};
+
+mod common;
+
+use common::{names, var};
+
+fn majority() -> Vec<Token> {
+    // a & b | a & c | b & c
+    vec![
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Or,
+        var("a"),
+        Token::And,
+        var("c"),
+        Token::Or,
+        var("b"),
+        Token::And,
+        var("c"),
+    ]
+}
+
+#[test]
+fn difference() {
+    let func = majority();
+    let table = parse(&func).unwrap();
+    let all = names(&["a", "b", "c"]);
+
+    // ∂maj/∂a = b ⊕ c
+    let difference = boolean_difference(&func, "a").unwrap();
+    assert_eq!(
+        parse_with_order(&difference, &names(&["b", "c"])),
+        Ok(vec![false, true, true, false])
+    );
+    assert_eq!(
+        boolean_difference_table(&table, &all, "a"),
+        Ok(vec![false, true, true, false])
+    );
+
+    assert_eq!(boolean_difference(&func, "x"), Ok(vec![Token::Zero]));
+    assert!(boolean_difference_table(&table, &all, "x").is_err());
+}
+
+#[test]
+fn influences() {
+    let table = parse(&majority()).unwrap();
+    assert_eq!(influence(&table), Ok(vec![0.5, 0.5, 0.5]));
+    assert_eq!(total_influence(&table), Ok(1.5));
+
+    // a | b & !b -> b has no influence
+    let table = parse(&[
+        var("a"),
+        Token::Or,
+        var("b"),
+        Token::And,
+        Token::Not,
+        var("b"),
+    ])
+    .unwrap();
+    assert_eq!(influence(&table), Ok(vec![1.0, 0.0]));
+
+    assert_eq!(influence(&[true]), Ok(vec![]));
+    assert!(influence(&[true, false, true]).is_err());
+}
+
+#[test]
+fn sensitivities() {
+    let table = parse(&majority()).unwrap();
+    assert_eq!(sensitivity(&table), Ok(2));
+    assert_eq!(block_sensitivity(&table), Ok(2));
+
+    // a ⊕ b ⊕ c
+    let table = parse(&[var("a"), Token::Xor, var("b"), Token::Xor, var("c")]).unwrap();
+    assert_eq!(sensitivity(&table), Ok(3));
+    assert_eq!(block_sensitivity(&table), Ok(3));
+
+    assert_eq!(sensitivity(&[false]), Ok(0));
+    assert_eq!(block_sensitivity(&[false, false]), Ok(0));
+}
+
+#[test]
+fn block_sensitivity_larger_than_sensitivity() {
+    // 0001 1011 1101 1000
+    let table: Vec<bool> = "0001101111011000".chars().map(|c| c == '1').collect();
+    assert_eq!(sensitivity(&table), Ok(2));
+    assert_eq!(block_sensitivity(&table), Ok(3));
+}
@@ -0,0 +1,108 @@
+use bool_algebra::{
+    autocorrelation, correlation_immunity, is_balanced, is_bent, nonlinearity, parse, resiliency,
+    walsh_spectrum, Token,
+};
+
+mod common;
+
+use common::var;
+
+fn str2_bool(input: &str) -> Vec<bool> {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c == '1')
+        .collect()
+}
+
+#[test]
+fn spectrum_matches_definition() {
+    // (a → b) ⊕ c & !d
+    let table = parse(&[
+        Token::Open,
+        var("a"),
+        Token::ImplicAB,
+        var("b"),
+        Token::Close,
+        Token::Xor,
+        var("c"),
+        Token::And,
+        Token::Not,
+        var("d"),
+    ])
+    .unwrap();
+    let spectrum = walsh_spectrum(&table).unwrap();
+
+    for (a, &w) in spectrum.iter().enumerate() {
+        let expected: i64 = (0..table.len())
+            .map(|x| {
+                let parity = (a & x).count_ones() % 2 == 1;
+                if table[x] ^ parity {
+                    -1
+                } else {
+                    1
+                }
+            })
+            .sum();
+        assert_eq!(w, expected, "at {}", a);
+    }
+
+    // Parseval: Σ W(a)² = 2^(2n)
+    let sum: i64 = spectrum.iter().map(|w| w * w).sum();
+    assert_eq!(sum, (table.len() * table.len()) as i64);
+}
+
+#[test]
+fn bent() {
+    // a & b ⊕ c & d is bent
+    let table = parse(&[
+        var("a"),
+        Token::And,
+        var("b"),
+        Token::Xor,
+        var("c"),
+        Token::And,
+        var("d"),
+    ])
+    .unwrap();
+    assert_eq!(is_bent(&table), Ok(true));
+    assert_eq!(nonlinearity(&table), Ok(6));
+    assert_eq!(is_balanced(&table), Ok(false));
+    assert_eq!(resiliency(&table), Ok(None));
+
+    // bent functions have a flat autocorrelation
+    let autocorrelation = autocorrelation(&table).unwrap();
+    assert_eq!(autocorrelation[0], 16);
+    assert!(autocorrelation[1..].iter().all(|&r| r == 0));
+
+    assert_eq!(is_bent(&str2_bool("0001 0111")), Ok(false));
+}
+
+#[test]
+fn immunity() {
+    // a ⊕ b ⊕ c & d is balanced and 1-resilient
+    let table = parse(&[
+        var("a"),
+        Token::Xor,
+        var("b"),
+        Token::Xor,
+        var("c"),
+        Token::And,
+        var("d"),
+    ])
+    .unwrap();
+    assert_eq!(is_balanced(&table), Ok(true));
+    assert_eq!(correlation_immunity(&table), Ok(1));
+    assert_eq!(resiliency(&table), Ok(Some(1)));
+
+    // constants are not correlated with anything
+    assert_eq!(correlation_immunity(&str2_bool("1111")), Ok(2));
+    assert_eq!(resiliency(&str2_bool("1111")), Ok(None));
+}
+
+#[test]
+fn invalid() {
+    assert!(walsh_spectrum(&[true, false, true]).is_err());
+    assert!(autocorrelation(&[]).is_err());
+    assert!(nonlinearity(&[true; 6]).is_err());
+}
@@ -0,0 +1,87 @@
+use bool_algebra::{compose, eval, parse, substitute, Token};
+use std::collections::HashMap;
+
+mod common;
+
+use common::var;
+
+#[test]
+fn precedence() {
+    // !a & b with a = c ≡ d -> !(c ≡ d) & b
+    let func = vec![Token::Not, var("a"), Token::And, var("b")];
+    let replacement = vec![var("c"), Token::Eq, var("d")];
+    assert_eq!(
+        substitute(&func, "a", &replacement),
+        Ok(vec![
+            Token::Not,
+            Token::Open,
+            var("c"),
+            Token::Eq,
+            var("d"),
+            Token::Close,
+            Token::And,
+            var("b"),
+        ])
+    );
+
+    // a ⊕ b with b = c & d -> a ⊕ c & d
+    let func = vec![var("a"), Token::Xor, var("b")];
+    let replacement = vec![var("c"), Token::And, var("d")];
+    assert_eq!(
+        substitute(&func, "b", &replacement),
+        Ok(vec![var("a"), Token::Xor, var("c"), Token::And, var("d")])
+    );
+}
+
+#[test]
+fn semantics() {
+    // (a → b) | a & !c with b = a ⊽ c
+    let func = vec![
+        Token::Open,
+        var("a"),
+        Token::ImplicAB,
+        var("b"),
+        Token::Close,
+        Token::Or,
+        var("a"),
+        Token::And,
+        Token::Not,
+        var("c"),
+    ];
+    let replacement = vec![var("a"), Token::Nor, var("c")];
+    let result = substitute(&func, "b", &replacement).unwrap();
+    assert!(!result.contains(&var("b")));
+
+    for i in 0..4 {
+        let mut values = HashMap::new();
+        values.insert("a".to_string(), i & 2 != 0);
+        values.insert("c".to_string(), i & 1 != 0);
+        let b = eval(&replacement, &values).unwrap();
+        values.insert("b".to_string(), b);
+        assert_eq!(eval(&result, &values), eval(&func, &values));
+    }
+}
+
+#[test]
+fn rename() {
+    let func = vec![var("a"), Token::And, Token::Not, var("b")];
+    let mut substitutions = HashMap::new();
+    substitutions.insert("a".to_string(), vec![var("x")]);
+    substitutions.insert("b".to_string(), vec![var("y")]);
+    substitutions.insert("unused".to_string(), vec![Token::One]);
+    assert_eq!(
+        compose(&func, &substitutions),
+        Ok(vec![var("x"), Token::And, Token::Not, var("y")])
+    );
+    assert_eq!(
+        parse(&compose(&func, &substitutions).unwrap()),
+        parse(&func)
+    );
+}
+
+#[test]
+fn invalid() {
+    let func = vec![var("a"), Token::And, var("b")];
+    assert!(substitute(&func, "a", &[Token::And]).is_err());
+    assert!(substitute(&[Token::And], "a", &[var("b")]).is_err());
+}
@@ -0,0 +1,64 @@
+use bool_algebra::{project_table, support, Token};
+
+#[test]
+fn support_all() {
+    // a & b
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::And,
+        Token::Var("b".to_string()),
+    ];
+    assert_eq!(support(&func), Ok(vec!["a".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn support_irrelevant() {
+    // (a | !a) & c ⊕ (b ⊕ b)
+    let func = vec![
+        Token::Open,
+        Token::Var("a".to_string()),
+        Token::Or,
+        Token::Not,
+        Token::Var("a".to_string()),
+        Token::Close,
+        Token::And,
+        Token::Var("c".to_string()),
+        Token::Xor,
+        Token::Open,
+        Token::Var("b".to_string()),
+        Token::Xor,
+        Token::Var("b".to_string()),
+        Token::Close,
+    ];
+    assert_eq!(support(&func), Ok(vec!["c".to_string()]));
+}
+
+#[test]
+fn support_const() {
+    // a | !a
+    let func = vec![
+        Token::Var("a".to_string()),
+        Token::Or,
+        Token::Not,
+        Token::Var("a".to_string()),
+    ];
+    assert_eq!(support(&func), Ok(vec![]));
+    assert!(support(&[Token::And]).is_err());
+}
+
+#[test]
+fn project() {
+    // b only
+    let table = vec![false, true, false, true];
+    let names = vec!["a".to_string(), "b".to_string()];
+    assert_eq!(
+        project_table(&table, &names),
+        Ok((vec![false, true], vec!["b".to_string()]))
+    );
+
+    // constant
+    let table = vec![true, true, true, true];
+    assert_eq!(project_table(&table, &names), Ok((vec![true], vec![])));
+
+    assert!(project_table(&[true, false], &names).is_err());
+}
@@ -0,0 +1,73 @@
+use bool_algebra::{
+    exactly, majority, parse, parse_with_order, symmetric_table, symmetries, threshold, Token,
+};
+
+mod common;
+
+use common::{names, var};
+
+fn pair(a: &str, b: &str) -> (String, String) {
+    (a.to_string(), b.to_string())
+}
+
+#[test]
+fn total() {
+    let all = names(&["a", "b", "c", "d"]);
+    let table = parse(&majority(&all).unwrap()).unwrap();
+    let symmetries = symmetries(&table, &all).unwrap();
+    assert!(symmetries.total);
+    assert_eq!(symmetries.symmetric.len(), 6);
+
+    let symmetries = bool_algebra::symmetries(&[true, false], &names(&["a"])).unwrap();
+    assert!(symmetries.total);
+}
+
+#[test]
+fn anti_symmetric() {
+    // !a | b | c -> b, c can be swapped and a can be swapped with !b or !c
+    let all = names(&["a", "b", "c"]);
+    let table = parse(&[
+        Token::Open,
+        var("a"),
+        Token::ImplicAB,
+        var("b"),
+        Token::Close,
+        Token::Or,
+        var("c"),
+    ])
+    .unwrap();
+    let symmetries = symmetries(&table, &all).unwrap();
+    assert!(!symmetries.total);
+    assert_eq!(symmetries.symmetric, vec![pair("b", "c")]);
+    assert_eq!(
+        symmetries.anti_symmetric,
+        vec![pair("a", "b"), pair("a", "c")]
+    );
+
+    // a ⊕ b is both
+    let table = parse(&[var("a"), Token::Xor, var("b")]).unwrap();
+    let symmetries = bool_algebra::symmetries(&table, &names(&["a", "b"])).unwrap();
+    assert_eq!(symmetries.symmetric, vec![pair("a", "b")]);
+    assert_eq!(symmetries.anti_symmetric, vec![pair("a", "b")]);
+}
+
+#[test]
+fn constructors() {
+    let all = names(&["a", "b", "c"]);
+    assert_eq!(
+        parse_with_order(&threshold(&all, 2).unwrap(), &all),
+        Ok(symmetric_table(3, &[2, 3]))
+    );
+    assert_eq!(
+        parse_with_order(&exactly(&all, 1).unwrap(), &all),
+        Ok(vec![false, true, true, false, true, false, false, false])
+    );
+    assert_eq!(threshold(&all, 0), Ok(vec![Token::One]));
+    assert_eq!(exactly(&all, 4), Ok(vec![Token::Zero]));
+    assert_eq!(symmetric_table(0, &[0]), vec![true]);
+}
+
+#[test]
+fn invalid() {
+    assert!(symmetries(&[true, false], &names(&["a", "b"])).is_err());
+}
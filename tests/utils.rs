@@ -1,3 +1,9 @@
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::unnecessary_to_owned,
+    clippy::useless_vec
+)]
+
 use bool_algebra::Token;
 
 #[test]